@@ -0,0 +1,60 @@
+//! Image URLs of AnimeSama chapters.
+//!
+//! The CDN stores a chapter under its position in the series' chapter list, not
+//! under its number: the chapter after a one-shot lives one folder further.
+//! Chapter keys hold the number, and the position is read from the track's
+//! chapter list when pages are requested. Which CDN and file layout a series
+//! uses is not published anywhere, so the first page of each candidate layout
+//! is requested and the first one that answers is used for the whole chapter.
+
+use aidoku::{
+	AidokuError, Result,
+	alloc::{String, Vec, format, string::ToString},
+	imports::net::Request,
+};
+
+use crate::{BASE_URL, CDN_URL, CDN_URL_LEGACY, drive, helper};
+
+/// Candidate layouts, `{index}` and `{page}` being substituted. Tried in order.
+const URL_PATTERNS: [(&str, &str); 3] = [
+	(CDN_URL, "{index}/{page}.jpg"),
+	(CDN_URL_LEGACY, "{index}/{page}.jpg"),
+	(CDN_URL_LEGACY, "{index}/{index}_{page}.jpg"),
+];
+
+fn image_url(cdn_url: &str, pattern: &str, title: &str, index: i32, page: i32) -> String {
+	let path = pattern
+		.replace("{index}", &index.to_string())
+		.replace("{page}", &page.to_string());
+	format!("{}/{}/{}", cdn_url, helper::urlencode_path(title), path)
+}
+
+fn url_exists(url: &str) -> bool {
+	Request::get(url)
+		.map(|request| request.header("Referer", BASE_URL))
+		.and_then(|request| request.send())
+		.is_ok_and(|response| (200..300).contains(&response.status_code()))
+}
+
+/// URLs of the `page_count` pages of the chapter at `index` of the series whose
+/// CDN folder is `title`.
+pub fn resolve_image_urls(title: &str, index: i32, page_count: i32) -> Result<Vec<String>> {
+	for (cdn_url, pattern) in URL_PATTERNS {
+		if url_exists(&image_url(cdn_url, pattern, title, index, 1)) {
+			return Ok((1..=page_count)
+				.map(|page| image_url(cdn_url, pattern, title, index, page))
+				.collect());
+		}
+	}
+	Err(AidokuError::message("Chapitre introuvable sur le CDN"))
+}
+
+/// Direct image URLs of an `epsN` array of the reader, as published for series
+/// that aren't laid out like the CDN. Google Drive links are left out.
+pub fn reader_image_urls(eps_urls: &[String]) -> Vec<String> {
+	eps_urls
+		.iter()
+		.filter(|url| url.starts_with("http") && !drive::is_drive_url(url))
+		.cloned()
+		.collect()
+}
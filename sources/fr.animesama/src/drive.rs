@@ -0,0 +1,93 @@
+//! Pages of AnimeSama chapters hosted on Google Drive.
+//!
+//! Some readers list Drive viewer links (`/file/d/ID/view`, `open?id=ID`), which
+//! return an HTML page rather than the image. They are turned into download
+//! URLs, or `lh3` image URLs when downloading isn't possible.
+
+use aidoku::{
+	alloc::{String, Vec, format, string::ToString},
+	imports::net::Request,
+};
+
+const DOWNLOAD_URL: &str = "https://drive.google.com/uc?export=download&id=";
+const CONFIRMED_DOWNLOAD_URL: &str = "https://drive.usercontent.google.com/download?export=download&id=";
+const IMAGE_URL: &str = "https://lh3.googleusercontent.com/d/";
+
+/// Whether `url` points to Google Drive.
+pub fn is_drive_url(url: &str) -> bool {
+	url.contains("drive.google.com") || url.contains("docs.google.com")
+}
+
+/// File ID of a Drive link: `/file/d/ID/view`, `open?id=ID` or `uc?id=ID`.
+pub fn file_id(url: &str) -> Option<&str> {
+	let id = if let Some(pos) = url.find("/file/d/") {
+		&url[pos + "/file/d/".len()..]
+	} else {
+		let query = &url[url.find('?')? + 1..];
+		query.split('&').find_map(|param| param.strip_prefix("id="))?
+	};
+	let end = id.find(['/', '?', '&', '#']).unwrap_or(id.len());
+	Some(&id[..end]).filter(|id| !id.is_empty())
+}
+
+/// Confirm token of Drive's "can't scan this file for viruses" page, either as
+/// a `confirm=` link parameter or as the hidden `confirm` and `uuid` form fields.
+fn confirm_token(html: &str) -> Option<(String, Option<String>)> {
+	let field = |name: &str| {
+		let marker = format!("name=\"{}\" value=\"", name);
+		let start = html.find(&marker)? + marker.len();
+		let end = html[start..].find('"')?;
+		Some(html[start..start + end].to_string())
+	};
+	let link_token = || {
+		let start = html.find("confirm=")? + "confirm=".len();
+		let end = html[start..]
+			.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+			.unwrap_or(html.len() - start);
+		Some(html[start..start + end].to_string()).filter(|token| !token.is_empty())
+	};
+
+	field("confirm").or_else(link_token).map(|token| (token, field("uuid")))
+}
+
+/// Image URLs of the Drive links of a chapter.
+///
+/// The first file decides how all are served: downloaded directly when Drive
+/// sends the image, through the confirmed download URL when it asks for
+/// confirmation, and from `lh3` otherwise.
+pub fn image_urls(urls: &[String]) -> Vec<String> {
+	let ids: Vec<&str> = urls.iter().filter_map(|url| file_id(url)).collect();
+	let Some(first_id) = ids.first() else {
+		return Vec::new();
+	};
+
+	let response = Request::get(format!("{}{}", DOWNLOAD_URL, first_id))
+		.and_then(|request| request.send())
+		.ok()
+		.filter(|response| (200..300).contains(&response.status_code()));
+	let Some(response) = response else {
+		return ids.iter().map(|id| format!("{}{}", IMAGE_URL, id)).collect();
+	};
+
+	let is_html = response
+		.get_header("Content-Type")
+		.is_some_and(|content_type| content_type.contains("text/html"));
+	if !is_html {
+		return ids.iter().map(|id| format!("{}{}", DOWNLOAD_URL, id)).collect();
+	}
+
+	match response.get_string().ok().as_deref().and_then(confirm_token) {
+		// The token isn't tied to a file, only the uuid is, so it is dropped for the others
+		Some((token, uuid)) => ids
+			.iter()
+			.enumerate()
+			.map(|(position, id)| match &uuid {
+				Some(uuid) if position == 0 => {
+					format!("{}{}&confirm={}&uuid={}", CONFIRMED_DOWNLOAD_URL, id, token, uuid)
+				}
+				_ => format!("{}{}&confirm={}", CONFIRMED_DOWNLOAD_URL, id, token),
+			})
+			.collect(),
+		None => ids.iter().map(|id| format!("{}{}", IMAGE_URL, id)).collect(),
+	}
+}
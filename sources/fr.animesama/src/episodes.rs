@@ -0,0 +1,354 @@
+//! Interpréteur de la liste des chapitres d'une piste de scans.
+//!
+//! Les pages de scans décrivent leurs chapitres avec trois appels JavaScript,
+//! exécutés dans l'ordre et qui numérotent les positions du CDN une à une :
+//!
+//! - `creerListe(1, 50);` : chapitres 1 à 50 ;
+//! - `newSP(50.5);` ou `newSP("One Shot");` : un chapitre spécial ;
+//! - `finirListe(51);` : toutes les positions restantes, numérotées à partir de 51.
+//!
+//! Un chapitre spécial « Fin » marque une série terminée.
+//!
+//! Les appels peuvent s'étaler sur plusieurs lignes ou être commentés, le
+//! script est donc découpé en jetons plutôt que lu ligne par ligne.
+
+use aidoku::{
+	MangaStatus,
+	alloc::{String, Vec, format, string::ToString},
+};
+
+/// Chapitre déclaré par le script, à la position `index` (à partir de 1) du CDN.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Episode {
+	pub index: i32,
+	/// Absent pour les chapitres spéciaux nommés (`newSP("One Shot")`).
+	pub number: Option<f32>,
+	pub title: String,
+}
+
+impl Episode {
+	/// Key of the chapter in its track: its number (`12`, `19_5` for 19.5), `9999`
+	/// for a one-shot and the label of other named chapters (`fin`). Keys don't
+	/// change when a chapter is inserted before them, unlike positions.
+	pub fn key(&self) -> String {
+		match self.number {
+			Some(number) if number.fract() == 0.0 => format!("{}", number as i32),
+			Some(number) => format!("{}_{}", number as i32, (number.fract() * 10.0).round() as i32),
+			None => {
+				let label = self.title.strip_prefix("Chapitre ").unwrap_or(&self.title).to_lowercase();
+				let words: Vec<&str> = label.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).collect();
+				if words.concat() == "oneshot" {
+					String::from("9999")
+				} else {
+					words.join("-")
+				}
+			}
+		}
+	}
+}
+
+/// Position on the CDN of the chapter with `key`.
+pub fn find_index(episodes: &[Episode], key: &str) -> Option<i32> {
+	episodes.iter().find(|episode| episode.key() == key).map(|episode| episode.index)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'a> {
+	Ident(&'a str),
+	Number(f32),
+	Str(String),
+	Punct(char),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Arg {
+	Number(f32),
+	Text(String),
+}
+
+/// Découpe `script` en jetons, sans les commentaires ni les espaces.
+fn tokenize(script: &str) -> Vec<Token<'_>> {
+	let mut tokens = Vec::new();
+	let mut pos = 0;
+
+	while pos < script.len() {
+		let rest = &script[pos..];
+		let c = rest.chars().next().unwrap_or_default();
+
+		if c.is_whitespace() {
+			pos += c.len_utf8();
+		} else if rest.starts_with("//") {
+			pos += rest.find('\n').unwrap_or(rest.len());
+		} else if let Some(comment) = rest.strip_prefix("/*") {
+			pos += comment.find("*/").map(|end| end + 4).unwrap_or(rest.len());
+		} else if matches!(c, '"' | '\'' | '`') {
+			let mut value = String::new();
+			let mut escaped = false;
+			let mut end = rest.len();
+			for (offset, ch) in rest.char_indices().skip(1) {
+				if escaped {
+					value.push(ch);
+					escaped = false;
+				} else if ch == '\\' {
+					escaped = true;
+				} else if ch == c {
+					end = offset + 1;
+					break;
+				} else if ch == '\n' && c != '`' {
+					// Pas une chaîne (expression régulière, apostrophe dans du HTML...)
+					end = offset;
+					break;
+				} else {
+					value.push(ch);
+				}
+			}
+			tokens.push(Token::Str(value));
+			pos += end;
+		} else if c.is_ascii_digit() {
+			let len = rest
+				.find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+				.unwrap_or(rest.len());
+			if let Ok(number) = rest[..len].parse::<f32>() {
+				tokens.push(Token::Number(number));
+			}
+			pos += len;
+		} else if c.is_alphabetic() || c == '_' || c == '$' {
+			let len = rest
+				.find(|ch: char| !(ch.is_alphanumeric() || ch == '_' || ch == '$'))
+				.unwrap_or(rest.len());
+			tokens.push(Token::Ident(&rest[..len]));
+			pos += len;
+		} else {
+			tokens.push(Token::Punct(c));
+			pos += c.len_utf8();
+		}
+	}
+
+	tokens
+}
+
+/// Arguments littéraux de l'appel dont la parenthèse ouvrante est `tokens[0]`,
+/// avec le nombre de jetons consommés. `None` si un argument n'est pas un
+/// littéral, comme dans la définition `function creerListe(debut, fin)`.
+fn read_args(tokens: &[Token<'_>]) -> Option<(Vec<Arg>, usize)> {
+	if tokens.first() != Some(&Token::Punct('(')) {
+		return None;
+	}
+
+	let mut args = Vec::new();
+	let mut expect_arg = true;
+	for (offset, token) in tokens.iter().enumerate().skip(1) {
+		match token {
+			Token::Punct(')') => return Some((args, offset + 1)),
+			Token::Punct(',') if !expect_arg => expect_arg = true,
+			Token::Number(number) if expect_arg => {
+				args.push(Arg::Number(*number));
+				expect_arg = false;
+			}
+			Token::Str(text) if expect_arg => {
+				// Le site écrit parfois les numéros entre guillemets
+				let text = text.trim();
+				args.push(match text.parse::<f32>() {
+					Ok(number) => Arg::Number(number),
+					Err(_) => Arg::Text(text.to_string()),
+				});
+				expect_arg = false;
+			}
+			_ => return None,
+		}
+	}
+	None
+}
+
+fn chapter_title(number: f32) -> String {
+	format!("Chapitre {}", number)
+}
+
+fn push(episodes: &mut Vec<Episode>, number: Option<f32>, title: String) {
+	let index = episodes.len() as i32 + 1;
+	episodes.push(Episode { index, number, title });
+}
+
+/// Appels de la liste (`creerListe`, `newSP`, `finirListe`) de `script`, dans
+/// l'ordre, avec leurs arguments littéraux.
+fn parse_calls(script: &str) -> Vec<(&str, Vec<Arg>)> {
+	let tokens = tokenize(script);
+	let mut calls = Vec::new();
+
+	let mut pos = 0;
+	while pos < tokens.len() {
+		let Token::Ident(name) = tokens[pos] else {
+			pos += 1;
+			continue;
+		};
+		// `obj.newSP(...)` ou `function newSP(...)` ne sont pas des appels de la liste
+		let is_call = pos == 0 || !matches!(tokens[pos - 1], Token::Punct('.') | Token::Ident("function"));
+		if !is_call || !matches!(name, "creerListe" | "newSP" | "finirListe") {
+			pos += 1;
+			continue;
+		}
+		let Some((args, consumed)) = read_args(&tokens[pos + 1..]) else {
+			pos += 1;
+			continue;
+		};
+		pos += 1 + consumed;
+		calls.push((name, args));
+	}
+
+	calls
+}
+
+/// Chapitres déclarés par `script` pour une piste de `total` positions.
+///
+/// Les positions que le script ne nomme pas (`total` plus grand que la liste
+/// déclarée, sans `finirListe`) continuent la numérotation du dernier chapitre.
+pub fn parse_episodes(script: &str, total: i32) -> Vec<Episode> {
+	let mut episodes: Vec<Episode> = Vec::new();
+	let mut finish_from: Option<f32> = None;
+
+	for (name, args) in parse_calls(script) {
+		match (name, args.as_slice()) {
+			("creerListe", [Arg::Number(start), Arg::Number(end)]) => {
+				let (start, end) = (*start as i32, *end as i32);
+				for number in start..=end {
+					push(&mut episodes, Some(number as f32), chapter_title(number as f32));
+				}
+			}
+			("newSP", [Arg::Number(number)]) => {
+				push(&mut episodes, Some(*number), chapter_title(*number));
+			}
+			("newSP", [Arg::Text(label)]) => {
+				push(&mut episodes, None, format!("Chapitre {}", label));
+			}
+			("finirListe", [Arg::Number(start)]) => {
+				finish_from = Some(start.floor());
+			}
+			_ => {}
+		}
+	}
+
+	let mut next_number = finish_from.unwrap_or_else(|| {
+		episodes
+			.iter()
+			.rev()
+			.find_map(|episode| episode.number)
+			.map(|number| number.floor() + 1.0)
+			.unwrap_or(1.0)
+	});
+	while (episodes.len() as i32) < total {
+		push(&mut episodes, Some(next_number), chapter_title(next_number));
+		next_number += 1.0;
+	}
+
+	episodes
+}
+
+/// Statut de publication d'après la liste : un chapitre spécial « Fin » la clôt,
+/// `finirListe` la laisse ouverte aux prochains chapitres.
+pub fn parse_status(script: &str) -> MangaStatus {
+	let calls = parse_calls(script);
+	let is_end_label = |label: &str| {
+		label
+			.split(|c: char| !c.is_alphanumeric())
+			.any(|word| matches!(word.to_lowercase().as_str(), "fin" | "end" | "final" | "finale"))
+	};
+
+	let finished = calls.iter().rev().find_map(|(name, args)| match (*name, args.as_slice()) {
+		("newSP", [Arg::Text(label)]) => Some(is_end_label(label)),
+		("finirListe", _) => Some(false),
+		_ => None,
+	});
+	if finished == Some(true) {
+		MangaStatus::Completed
+	} else if calls.iter().any(|(name, _)| *name == "finirListe") {
+		MangaStatus::Ongoing
+	} else {
+		MangaStatus::Unknown
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn numbers(episodes: &[Episode]) -> Vec<Option<f32>> {
+		episodes.iter().map(|episode| episode.number).collect()
+	}
+
+	#[test]
+	fn multi_line_and_commented_calls() {
+		let script = "
+			creerListe(
+				1,
+				3
+			);
+			// creerListe(4, 10);
+			/* newSP(3.5);
+			   finirListe(4); */
+			newSP(3.5);
+		";
+		let episodes = parse_episodes(script, 0);
+		assert_eq!(numbers(&episodes), vec![Some(1.0), Some(2.0), Some(3.0), Some(3.5)]);
+		assert_eq!(episodes[3].index, 4);
+	}
+
+	#[test]
+	fn specials_take_a_position() {
+		let episodes = parse_episodes("creerListe(1, 50); newSP(50.5); newSP(\"One Shot\"); creerListe(51, 52);", 0);
+		assert_eq!(episodes.len(), 54);
+		assert_eq!(episodes[50], Episode { index: 51, number: Some(50.5), title: "Chapitre 50.5".to_string() });
+		assert_eq!(episodes[51], Episode { index: 52, number: None, title: "Chapitre One Shot".to_string() });
+		assert_eq!(episodes[52].number, Some(51.0));
+		assert_eq!(episodes[52].index, 53);
+	}
+
+	#[test]
+	fn finir_liste_numbers_the_remaining_positions() {
+		let episodes = parse_episodes("creerListe(1, 2); newSP(\"One Shot\"); finirListe(3);", 5);
+		assert_eq!(numbers(&episodes), vec![Some(1.0), Some(2.0), None, Some(3.0), Some(4.0)]);
+		assert_eq!(episodes[4].index, 5);
+	}
+
+	#[test]
+	fn function_definitions_are_not_calls() {
+		let script = "
+			function creerListe(debut, fin) { for (var i = debut; i <= fin; i++) newSP(i); }
+			function newSP(numero) {}
+			function finirListe(debut) {}
+			creerListe(1, 2);
+		";
+		assert_eq!(numbers(&parse_episodes(script, 0)), vec![Some(1.0), Some(2.0)]);
+	}
+
+	#[test]
+	fn quoted_numbers_are_numbers() {
+		let episodes = parse_episodes("creerListe('1', \"2\"); newSP(\" 2.5 \");", 0);
+		assert_eq!(numbers(&episodes), vec![Some(1.0), Some(2.0), Some(2.5)]);
+	}
+
+	#[test]
+	fn pads_up_to_the_total() {
+		let episodes = parse_episodes("creerListe(1, 2); newSP(2.5);", 5);
+		assert_eq!(numbers(&episodes), vec![Some(1.0), Some(2.0), Some(2.5), Some(3.0), Some(4.0)]);
+		assert_eq!(numbers(&parse_episodes("", 2)), vec![Some(1.0), Some(2.0)]);
+		// The total never removes declared chapters
+		assert_eq!(parse_episodes("creerListe(1, 3);", 1).len(), 3);
+	}
+
+	#[test]
+	fn keys_follow_the_chapter_numbers() {
+		let episodes = parse_episodes("creerListe(1, 2); newSP(2.5); newSP(\"One Shot\"); newSP(\"Fin\");", 0);
+		let keys: Vec<String> = episodes.iter().map(Episode::key).collect();
+		assert_eq!(keys, vec!["1", "2", "2_5", "9999", "fin"]);
+		assert_eq!(find_index(&episodes, "2_5"), Some(3));
+		assert_eq!(find_index(&episodes, "9999"), Some(4));
+		assert_eq!(find_index(&episodes, "12"), None);
+	}
+
+	#[test]
+	fn status_from_the_list_markers() {
+		assert!(matches!(parse_status("creerListe(1, 10); newSP(\"Fin\");"), MangaStatus::Completed));
+		assert!(matches!(parse_status("creerListe(1, 10); finirListe(11);"), MangaStatus::Ongoing));
+		assert!(matches!(parse_status("creerListe(1, 10);"), MangaStatus::Unknown));
+	}
+}
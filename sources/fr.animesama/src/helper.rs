@@ -2,8 +2,15 @@ use aidoku::{
 	alloc::{String, Vec, format, string::ToString},
 };
 
-pub const SCAN_VF_PATH: &str = "/scan/vf/";
-pub const SCAN_BW_PATH: &str = "/scan_noir-et-blanc/vf/";
+/// Scan track used when a series page declares none
+pub const DEFAULT_SCAN_PATH: &str = "scan/vf";
+
+/// Black and white track, the one One Piece chapters were read from before
+/// tracks were told apart
+const BLACK_AND_WHITE_SCAN_PATH: &str = "scan_noir-et-blanc/vf";
+
+/// Setting holding the path of the scan track listed first (`toutes` for the site order)
+pub const PREFERRED_VARIANT_SETTING: &str = "preferredScanVariant";
 
 /// Encodes a string for use in URL query parameters
 /// Uses `+` for spaces (standard URL encoding for form data)
@@ -49,6 +56,33 @@ pub fn urlencode_path(text: &str) -> String {
 	result
 }
 
+/// Decodes a URL query value (`%C3%A9` and `+` for spaces)
+pub fn urldecode(text: &str) -> String {
+	let bytes = text.as_bytes();
+	let mut result: Vec<u8> = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+
+	while i < bytes.len() {
+		match bytes[i] {
+			b'+' => result.push(b' '),
+			b'%' if i + 2 < bytes.len() => {
+				let hex = |byte: u8| (byte as char).to_digit(16);
+				match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+					(Some(high), Some(low)) => {
+						result.push((high * 16 + low) as u8);
+						i += 2;
+					}
+					_ => result.push(b'%'),
+				}
+			}
+			byte => result.push(byte),
+		}
+		i += 1;
+	}
+
+	String::from_utf8(result).unwrap_or_else(|_| text.to_string())
+}
+
 pub fn i32_to_string(num: i32) -> String {
 	let mut result = String::new();
 	let mut n = num;
@@ -75,25 +109,62 @@ pub fn i32_to_string(num: i32) -> String {
 	result
 }
 
-/// Removes scan path suffixes from URLs
-/// Returns the cleaned URL without `/scan/vf/` or `/scan_noir-et-blanc/vf/`
+/// Removes the scan track from series URLs
+/// (`/catalogue/one-piece/scan_noir-et-blanc/vf/` -> `/catalogue/one-piece`)
 pub fn clean_url(url: &str) -> String {
-	if let Some(pos) = url.find(SCAN_VF_PATH) {
-		let mut result = String::from(&url[..pos]);
-		result.push_str(&url[pos + SCAN_VF_PATH.len()..]);
-		result
-	} else if let Some(pos) = url.find(SCAN_BW_PATH) {
-		let mut result = String::from(&url[..pos]);
-		result.push_str(&url[pos + SCAN_BW_PATH.len()..]);
-		result
+	let catalogue_end = url.find("/catalogue/").map(|pos| pos + "/catalogue/".len()).unwrap_or(0);
+	// The series slug itself may start with "scan"
+	let track_start = url[catalogue_end..]
+		.find('/')
+		.map(|slug_end| catalogue_end + slug_end)
+		.and_then(|slug_end| {
+			let rest = &url[slug_end..];
+			(rest.starts_with("/scan/") || rest.starts_with("/scan_")).then_some(slug_end)
+		});
+	match track_start {
+		Some(pos) => url[..pos].to_string(),
+		None => url.to_string(),
+	}
+}
+
+/// Track of the chapter keys without one: the only track read before tracks
+/// were told apart. Its chapters keep those keys so read history carries over.
+pub fn legacy_scan_path(manga_key: &str) -> &'static str {
+	let key = manga_key.to_lowercase();
+	if key.contains("one-piece") || key.contains("one_piece") {
+		BLACK_AND_WHITE_SCAN_PATH
+	} else {
+		DEFAULT_SCAN_PATH
+	}
+}
+
+/// Key of the chapter `number_key` (`12`, `19_5`) of a scan track: the bare
+/// number on the legacy track, prefixed by the track on the others
+/// (`scan_noir-et-blanc/vf/12`).
+pub fn chapter_key(manga_key: &str, path: &str, number_key: &str) -> String {
+	if path == legacy_scan_path(manga_key) {
+		number_key.to_string()
 	} else {
-		url.to_string()
+		format!("{}/{}", path, number_key)
 	}
 }
 
-/// Detects if a manga is One Piece based on its key (case-insensitive)
-/// One Piece requires special handling for scan paths
-pub fn is_one_piece_manga(manga_key: &str) -> bool {
-	let key_lower = manga_key.to_lowercase();
-	key_lower.contains("one-piece") || key_lower.contains("one_piece")
+/// Splits a chapter key into its scan track and its chapter number in the track,
+/// the reverse of `chapter_key`.
+pub fn split_chapter_key<'a>(manga_key: &str, key: &'a str) -> (&'a str, &'a str) {
+	match key.rsplit_once('/') {
+		Some((path, number_key)) => (path, number_key),
+		None => (legacy_scan_path(manga_key), key),
+	}
+}
+
+/// URL of a scan track page of a series
+pub fn scan_track_url(base_url: &str, manga_key: &str, path: &str) -> String {
+	let manga_key = clean_url(manga_key);
+	let manga_key = manga_key.trim_end_matches('/');
+	if manga_key.starts_with("http") {
+		format!("{}/{}/", manga_key, path)
+	} else {
+		format!("{}{}/{}/", base_url, manga_key, path)
+	}
 }
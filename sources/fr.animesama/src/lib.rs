@@ -4,7 +4,7 @@ use aidoku::{
 	Chapter, FilterValue, ImageRequestProvider, Listing, ListingProvider, Manga, MangaPageResult,
 	Page, PageContext, Result, Source,
 	alloc::{String, Vec, vec},
-	imports::{net::{Request, Response}, std::send_partial_result},
+	imports::{defaults::defaults_get, net::{Request, Response}, std::send_partial_result},
 	prelude::*,
 	AidokuError,
 };
@@ -16,6 +16,9 @@ use alloc::format;
 // Modules contenant la logique de parsing sophistiquée d'AnimeSama
 pub mod parser;
 pub mod helper;
+pub mod cdn;
+pub mod drive;
+pub mod episodes;
 
 pub const BASE_URL: &str = "https://anime-sama.org";
 pub const CDN_URL: &str = "https://anime-sama.org/s2/scans";
@@ -107,17 +110,15 @@ impl Source for AnimeSama {
 		page: i32,
 		filters: Vec<FilterValue>,
 	) -> Result<MangaPageResult> {
-		let mut filter_params = String::new();
+		// Le catalogue ne filtre que par type, langue et genre, sans tri, statut
+		// ni exclusion. Seuls les scans ont des chapitres : le type est fixé.
+		let mut filter_params = String::from("type%5B%5D=Scans");
 
 		for filter in &filters {
 			match filter {
-				FilterValue::MultiSelect { id, included, excluded: _ } => {
-					if id == "genre" && !included.is_empty() {
-						for genre_value in included {
-							if !genre_value.is_empty() {
-								filter_params.push_str(&format!("&genre%5B%5D={}", helper::urlencode(genre_value)));
-							}
-						}
+				FilterValue::MultiSelect { id, included, .. } => {
+					for value in included.iter().filter(|value| !value.is_empty()) {
+						filter_params.push_str(&format!("&{}%5B%5D={}", id, helper::urlencode(value)));
 					}
 				}
 				FilterValue::Text { id, value } => {
@@ -128,31 +129,21 @@ impl Source for AnimeSama {
 				_ => {}
 			}
 		}
-		
-		// Construire l'URL de recherche pour anime-sama.org
-		// Format attendu: type%5B%5D=Scans&genre%5B%5D=Genre&search=query&page=N
-		let url = if let Some(search_query) = query {
-			// Avec recherche
-			format!("{}/catalogue?type%5B%5D=Scans{}&search={}&page={}", 
-				BASE_URL, 
-				filter_params,
-				helper::urlencode(&search_query),
-				page
-			)
-		} else {
-			// Sans recherche mais avec search= vide pour correspondre au format du site
-			format!("{}/catalogue?type%5B%5D=Scans{}&search=&page={}", 
-				BASE_URL, 
-				filter_params,
-				page
-			)
-		};
-		
+
+		// Format attendu: type%5B%5D=Scans&langue%5B%5D=VF&genre%5B%5D=Genre&search=query&page=N
+		let url = format!(
+			"{}/catalogue?{}&search={}&page={}",
+			BASE_URL,
+			filter_params,
+			helper::urlencode(query.as_deref().unwrap_or_default()),
+			page
+		);
+
 		// Faire la requête HTTP avec headers réalistes et propagation d'erreur
 		let html = make_realistic_request(&url)?;
-		
+
 		// Parser les résultats
-		parser::parse_manga_list(html)
+		parser::parse_manga_list(html, page)
 	}
 
 	fn get_manga_update(
@@ -169,10 +160,29 @@ impl Source for AnimeSama {
 		};
 		let base_manga_url = clean_key;
 
+		// Les pistes de scans (VF, noir et blanc...) sont déclarées sur la page de la série,
+		// leurs chapitres sur la page de chaque piste
+		let html = make_realistic_request(&base_manga_url)?;
+		let mut variants = parser::parse_scan_variants(&html);
+		if variants.is_empty() {
+			variants.push(parser::ScanVariant {
+				name: String::from("Scans"),
+				path: String::from(helper::DEFAULT_SCAN_PATH),
+			});
+		}
+
+		// La piste préférée passe en tête, les autres restent listées
+		let preferred = defaults_get::<String>(helper::PREFERRED_VARIANT_SETTING).unwrap_or_default();
+		if let Some(position) = variants.iter().position(|variant| variant.path == preferred) {
+			let variant = variants.remove(position);
+			variants.insert(0, variant);
+		}
+
+		// Page de la première piste, lue pour le statut et gardée pour ses chapitres
+		let mut first_track_html = None;
+
 		if needs_details {
-			// Faire une requête pour récupérer les détails du manga (URL de base)
-			let html = make_realistic_request(&base_manga_url)?;
-			let detailed_manga = parser::parse_manga_details(manga.key.clone(), html)?;
+			let detailed_manga = parser::parse_manga_details(manga.key.clone(), &html)?;
 			
 			// Mettre à jour les champs du manga avec les détails récupérés
 			manga.title = detailed_manga.title;
@@ -182,19 +192,28 @@ impl Source for AnimeSama {
 			manga.url = detailed_manga.url;
 			manga.cover = detailed_manga.cover;
 			manga.tags = detailed_manga.tags;
-			manga.status = detailed_manga.status;
 			manga.content_rating = detailed_manga.content_rating;
 			manga.viewer = detailed_manga.viewer;
 
+			let track_url = helper::scan_track_url(BASE_URL, &manga.key, &variants[0].path);
+			let track_html = make_realistic_request(&track_url)?;
+			manga.status = parser::parse_scan_status(&track_html);
+			first_track_html = Some(track_html);
+
 			if needs_chapters {
 				send_partial_result(&manga);
 			}
 		}
 
 		if needs_chapters {
-			// Pour les chapitres, utiliser aussi l'URL de base (le JavaScript est sur la page principale)
-			let html = make_realistic_request(&base_manga_url)?;
-			let chapters = parser::parse_chapter_list(manga.key.clone(), html)?;
+			let mut chapters = Vec::new();
+			for (position, variant) in variants.iter().enumerate() {
+				let track_html = match first_track_html.take().filter(|_| position == 0) {
+					Some(track_html) => track_html,
+					None => make_realistic_request(&helper::scan_track_url(BASE_URL, &manga.key, &variant.path))?,
+				};
+				chapters.extend(parser::parse_chapter_list(manga.key.clone(), variant, track_html)?);
+			}
 			manga.chapters = Some(chapters);
 		}
 
@@ -203,39 +222,15 @@ impl Source for AnimeSama {
 
 	fn get_page_list(&self, manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
 		let chapter_url = chapter.url.unwrap_or_else(|| {
-			let clean_manga_key = helper::clean_url(&manga.key);
-
-			let is_one_piece = helper::is_one_piece_manga(&clean_manga_key);
-			let scan_path = if is_one_piece {
-				if let Ok(chapter_num) = chapter.key.parse::<i32>() {
-					if chapter_num >= 1046 {
-						helper::SCAN_VF_PATH
-					} else {
-						helper::SCAN_BW_PATH
-					}
-				} else {
-					helper::SCAN_BW_PATH
-				}
-			} else {
-				helper::SCAN_VF_PATH
-			};
-			
-			if clean_manga_key.starts_with("http") {
-				// Remove trailing slash from clean_manga_key if it exists to avoid double slash
-				let clean_key = clean_manga_key.trim_end_matches('/');
-				format!("{}{}?id={}", clean_key, scan_path, chapter.key)
-			} else {
-				// Remove trailing slash from clean_manga_key if it exists to avoid double slash
-				let clean_key = clean_manga_key.trim_end_matches('/');
-				format!("{}{}{}?id={}", BASE_URL, clean_key, scan_path, chapter.key)
-			}
+			let (path, _) = helper::split_chapter_key(&manga.key, &chapter.key);
+			helper::scan_track_url(BASE_URL, &manga.key, path)
 		});
 		
 		// Faire une requête pour récupérer la page du chapitre
 		let html = make_realistic_request(&chapter_url)?;
 		
 		// Parser les pages depuis le HTML ou utiliser la logique CDN
-		parser::parse_page_list(html, manga.key, chapter.key)
+		parser::parse_page_list(html, manga.key, chapter.key, &chapter_url)
 	}
 }
 
@@ -244,14 +239,19 @@ impl ListingProvider for AnimeSama {
 		match listing.id.as_str() {
 			"dernières-sorties" => {
 				// Faire une requête vers la page d'accueil pour les dernières sorties
-				let html = make_realistic_request(BASE_URL)?;
-				parser::parse_manga_listing(html, "Dernières Sorties")
+				let url = if page > 1 {
+					format!("{}/?page={}", BASE_URL, page)
+				} else {
+					String::from(BASE_URL)
+				};
+				let html = make_realistic_request(&url)?;
+				parser::parse_manga_listing(html, "Dernières Sorties", page)
 			},
 			"populaire" => {
 				// Faire une requête vers le catalogue pour les mangas populaires
 				let url = format!("{}/catalogue?type%5B%5D=Scans&search=&page={}", BASE_URL, page);
 				let html = make_realistic_request(&url)?;
-				parser::parse_manga_listing(html, "Populaire")
+				parser::parse_manga_listing(html, "Populaire", page)
 			},
 			_ => {
 				// Listing ID non reconnu, retourner résultat vide
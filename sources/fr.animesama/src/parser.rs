@@ -1,12 +1,12 @@
 use aidoku::{
 	AidokuError, Chapter, ContentRating, Manga, MangaPageResult, MangaStatus, Page, PageContent, Result,
 	Viewer,
-	alloc::{String, Vec, format, vec, string::ToString},
-	imports::html::Document,
+	alloc::{String, Vec, collections::BTreeMap, format, vec, string::ToString},
+	imports::html::{Document, Element},
 	imports::net::Request,
 };
 
-use crate::{BASE_URL, CDN_URL, CDN_URL_LEGACY, helper};
+use crate::{BASE_URL, CDN_URL, cdn, drive, episodes, helper};
 
 // Calculate content rating based on tags
 fn calculate_content_rating(tags: &[String]) -> ContentRating {
@@ -32,225 +32,134 @@ fn calculate_viewer(tags: &[String]) -> Viewer {
 	}
 }
 
-fn extract_title_from_html(html: &Document, manga_key: &str) -> String {
-	html.select("#titreOeuvre")
-		.and_then(|els| els.text())
-		.or_else(|| {
-			html.select("title").and_then(|els| els.text()).and_then(|title_text| {
-				if title_text.contains(" - ") {
-					Some(title_text.split(" - ").next()?.trim().to_string())
-				} else {
-					Some(title_text.trim().to_string())
-				}
-			})
-		})
-		.or_else(|| {
-			html.select("h1").and_then(|els| els.text())
-		})
-		.unwrap_or_else(|| manga_key_to_title(manga_key))
-}
-
-// Fonction pour déterminer quel CDN utiliser selon le manga
-fn select_cdn_url(manga_title: &str) -> &'static str {
-	// Mangas qui utilisent l'ancien CDN
-	match manga_title {
-		"One Piece" | "Dragon Ball" => CDN_URL_LEGACY,
-		_ => CDN_URL, // Nouveau CDN par défaut
+/// Contenu des scripts inline de la page
+fn inline_scripts(html: &Document) -> String {
+	let mut content = String::new();
+	if let Some(scripts) = html.select("script") {
+		for script in scripts {
+			if let Some(script_text) = script.data() {
+				content.push_str(&script_text);
+				content.push('\n');
+			}
+		}
 	}
+	content
 }
 
-// Structure pour stocker les mappings de chapitres depuis JavaScript
-#[derive(Debug, Clone)]
-struct ChapterMapping {
-	index: i32,
-	chapter_number: f32,
-	title: String,
+/// Texte du premier élément de `selector`, espaces normalisés
+fn select_text(html: &Document, selector: &str) -> Option<String> {
+	html.select(selector)
+		.and_then(|els| els.first())
+		.and_then(|el| el.text())
+		.map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+		.filter(|text| !text.is_empty())
 }
 
-// Structure pour stocker les informations de finirListe
-#[derive(Debug, Clone)]
-struct FinirListeInfo {
-	_placeholder: i32, // Placeholder pour structure future
+/// Titre affiché de la série : `#titreOeuvre`, puis `<title>` (« Kaiju N°8 - Scans »), puis `h1`
+fn extract_title_from_html(html: &Document) -> Option<String> {
+	select_text(html, "#titreOeuvre")
+		.or_else(|| {
+			select_text(html, "title")
+				.filter(|text| text.contains(" - "))
+				.and_then(|text| text.split(" - ").next().map(|title| title.trim().to_string()))
+		})
+		.or_else(|| select_text(html, "h1"))
 }
 
-// Parser les commandes JavaScript pour créer le mapping
-fn parse_chapter_mapping(html_content: &str) -> (Vec<ChapterMapping>, Option<FinirListeInfo>) {
-	let mut mappings: Vec<ChapterMapping> = Vec::new();
-	let mut current_index = 1; // Indices commencent à 1
-	let mut finir_liste_info: Option<FinirListeInfo> = None;
-	
-	// Parser ligne par ligne en respectant l'ordre des commandes
-	let lines: Vec<&str> = html_content.lines().collect();
-	
-	for line in lines {
-		let trimmed = line.trim();
-		
-		// Parser tous les patterns sur la même ligne dans l'ordre
-		let mut line_pos = 0;
-		
-		while line_pos < trimmed.len() {
-			// Chercher le prochain pattern sur cette ligne
-			let creer_pos = trimmed[line_pos..].find("creerListe(");
-			let newsp_pos = trimmed[line_pos..].find("newSP(");
-			let finir_pos = trimmed[line_pos..].find("finirListe(");
-			
-			// Déterminer quel pattern vient en premier
-			let next_pattern = match (creer_pos, newsp_pos, finir_pos) {
-				(Some(c), Some(n), Some(f)) => {
-					if c < n && c < f { ("creer", line_pos + c) }
-					else if n < f { ("newsp", line_pos + n) }
-					else { ("finir", line_pos + f) }
-				}
-				(Some(c), Some(_n), None) if c < _n => ("creer", line_pos + c),
-				(Some(_c), Some(n), None) => ("newsp", line_pos + n),
-				(Some(c), None, Some(f)) if c < f => ("creer", line_pos + c),
-				(Some(_c), None, Some(f)) => ("finir", line_pos + f),
-				(None, Some(n), Some(f)) if n < f => ("newsp", line_pos + n),
-				(None, Some(_n), Some(f)) => ("finir", line_pos + f),
-				(Some(c), None, None) => ("creer", line_pos + c),
-				(None, Some(n), None) => ("newsp", line_pos + n),
-				(None, None, Some(f)) => ("finir", line_pos + f),
-				_ => break, // Aucun pattern trouvé
-			};
-			
-			match next_pattern.0 {
-				"creer" => {
-					let start_pos = next_pattern.1 + "creerListe(".len();
-					if let Some(params_end) = trimmed[start_pos..].find(");") {
-						let params = &trimmed[start_pos..start_pos + params_end];
-						let parts: Vec<&str> = params.split(",").map(|s| s.trim()).collect();
-						
-						if parts.len() == 2 {
-							if let (Ok(start), Ok(end)) = (parts[0].parse::<i32>(), parts[1].parse::<i32>()) {
-								// Créer les mappings pour la plage
-								for chapter_num in start..=end {
-									mappings.push(ChapterMapping {
-										index: current_index,
-										chapter_number: chapter_num as f32,
-										title: format!("Chapitre {}", chapter_num),
-									});
-									current_index += 1;
-								}
-							}
-						}
-						line_pos = start_pos + params_end + 2; // +2 pour ");
-					} else {
-						break;
-					}
-				}
-				"newsp" => {
-					let start_pos = next_pattern.1 + "newSP(".len();
-					if let Some(params_end) = trimmed[start_pos..].find(");") {
-						let param = trimmed[start_pos..start_pos + params_end].trim();
-						
-						// Cas 1: Chapitre avec label texte comme "One Shot"
-						if param.starts_with("\"") && param.ends_with("\"") && param.len() > 2 {
-							let text_content = &param[1..param.len()-1];
-							mappings.push(ChapterMapping {
-								index: current_index,
-								chapter_number: current_index as f32, // Utiliser l'index comme numéro
-								title: format!("Chapitre {}", text_content),
-							});
-							current_index += 1;
-						}
-						// Cas 2: Gérer les nombres décimaux comme 19.5
-						else if let Ok(special_num) = param.parse::<f32>() {
-							mappings.push(ChapterMapping {
-								index: current_index,
-								chapter_number: special_num,
-								title: format!("Chapitre {}", special_num),
-							});
-							current_index += 1;
-						}
-						line_pos = start_pos + params_end + 2; // +2 pour ");
-					} else {
-						break;
-					}
-				}
-				"finir" => {
-					let start_pos = next_pattern.1 + "finirListe(".len();
-					if let Some(params_end) = trimmed[start_pos..].find(");") {
-						let param = trimmed[start_pos..start_pos + params_end].trim();
-						
-						// Parser le paramètre de finirListe (devrait être un nombre)
-						if let Ok(_finir_start) = param.parse::<i32>() {
-							finir_liste_info = Some(FinirListeInfo {
-								_placeholder: 0,
-							});
-						}
-						line_pos = start_pos + params_end + 2; // +2 pour ");
-					} else {
-						break;
-					}
-				}
-				_ => break,
-			}
-		}
-	}
-	
-	(mappings, finir_liste_info)
-}
+/// Nom du dossier de la série sur le CDN, tel que la page l'utilise : paramètre
+/// `oeuvre=` de ses appels à l'API, sinon variable de script (`var nomOeuvre = "..."`),
+/// sinon titre affiché et enfin slug de la série
+fn extract_cdn_title(html: &Document, manga_key: &str) -> String {
+	let script_content = inline_scripts(html);
+
+	let from_api_call = script_content.match_indices("oeuvre=").find_map(|(pos, _)| {
+		let value = &script_content[pos + "oeuvre=".len()..];
+		let end = value.find(['"', '\'', '`', '&', '#']).unwrap_or(value.len());
+		// `oeuvre=" + nom` : la valeur est dans une variable
+		let title = helper::urldecode(value[..end].trim());
+		Some(title).filter(|title| !title.is_empty() && !title.contains(['+', '$', '{', '\n']))
+	});
 
-// Fonction calculate_chapter_number_for_index supprimée - non utilisée
+	let from_variable = || {
+		script_content.match_indices("Oeuvre").find_map(|(pos, _)| {
+			let value = script_content[pos + "Oeuvre".len()..].trim_start().strip_prefix('=')?.trim_start();
+			let quote = value.chars().next().filter(|c| matches!(c, '"' | '\'' | '`'))?;
+			let end = value[1..].find(quote)?;
+			Some(value[1..=end].trim().to_string()).filter(|title| !title.is_empty())
+		})
+	};
 
-// Chercher des chapitres décimaux dans une ligne HTML/JavaScript
-fn find_decimal_chapter_in_line(line: &str) -> Option<f32> {
-	// Chercher des patterns comme "19.5", "20.5" dans du JavaScript ou HTML
-	// Utiliser une approche plus simple avec regex-like matching
-	
-	let chars: Vec<char> = line.chars().collect();
-	let mut i = 0;
-	
-	while i < chars.len() {
-		// Chercher un chiffre
-		if chars[i].is_ascii_digit() {
-			let mut number_str = String::new();
-			
-			// Collecter tous les chiffres consécutifs
-			while i < chars.len() && chars[i].is_ascii_digit() {
-				number_str.push(chars[i]);
-				i += 1;
-			}
-			
-			// Vérifier s'il y a un point décimal
-			if i < chars.len() && chars[i] == '.' {
-				number_str.push('.');
-				i += 1;
-				
-				// Collecter les chiffres après le point
-				let decimal_start = number_str.len();
-				while i < chars.len() && chars[i].is_ascii_digit() {
-					number_str.push(chars[i]);
-					i += 1;
-				}
-				
-				// Vérifier qu'on a bien des chiffres après le point
-				if number_str.len() > decimal_start {
-					if let Ok(decimal_num) = number_str.parse::<f32>() {
-						// Vérifier que c'est un chapitre plausible
-						if decimal_num >= 1.0 && decimal_num <= 999.9 {
-							// Vérifier que c'est bien .5 (pas .0, .1, .9, etc.)
-							let fractional_part = decimal_num - (decimal_num as i32 as f32);
-							if (fractional_part - 0.5).abs() < 0.01 {
-								return Some(decimal_num);
-							}
-						}
-					}
-				}
-			}
-		} else {
-			i += 1;
-		}
-	}
-	
-	None
+	from_api_call
+		.or_else(from_variable)
+		.or_else(|| extract_title_from_html(html))
+		.unwrap_or_else(|| {
+			manga_key
+				.trim_end_matches('/')
+				.rsplit('/')
+				.next()
+				.unwrap_or_default()
+				.to_string()
+		})
 }
 
+// Fonction calculate_chapter_number_for_index supprimée - non utilisée
+
 // Fonction find_special_chapter_in_line supprimée - non utilisée
 
 // Version simplifiée des fonctions de parsing pour AnimeSama
 
-pub fn parse_manga_list(html: Document) -> Result<MangaPageResult> {
+/// Genres d'une carte du catalogue, lus dans sa ligne « Genres »
+fn parse_card_genres(element: &Element) -> Vec<String> {
+	element
+		.select(".info-row")
+		.and_then(|rows| {
+			rows.into_iter().find(|row| {
+				row.select(".info-label")
+					.and_then(|label| label.text())
+					.is_some_and(|label| label.to_lowercase().contains("genre"))
+			})
+		})
+		.and_then(|row| row.select(".info-value").and_then(|value| value.text()))
+		.map(|genres| {
+			genres
+				.split(',')
+				.map(|genre| genre.trim().to_string())
+				.filter(|genre| !genre.is_empty())
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+/// Le pager de la page (catalogue, accueil) va-t-il au-delà de `page` ?
+fn has_next_catalogue_page(html: &Document, page: i32) -> bool {
+	html.select("#list_pagination a, .pagination a")
+		.map(|links| {
+			links.into_iter().any(|link| {
+				link.text()
+					.and_then(|text| text.trim().parse::<i32>().ok())
+					.is_some_and(|number| number > page)
+			})
+		})
+		.unwrap_or(false)
+}
+
+/// « Chapitre 1120 » d'après le texte d'une carte de sortie (« Chapitre 1120 », « Chap. 45.5 »)
+fn extract_release_label(text: &str) -> Option<String> {
+	let lower = text.to_lowercase();
+	["chapitre", "chap.", "chap"].iter().find_map(|keyword| {
+		lower.match_indices(keyword).find_map(|(pos, _)| {
+			let rest = lower[pos + keyword.len()..].trim_start();
+			let end = rest
+				.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+				.unwrap_or(rest.len());
+			let number = rest[..end].trim_end_matches(['.', ',']).replace(',', ".");
+			number.parse::<f32>().ok().map(|_| format!("Chapitre {}", number))
+		})
+	})
+}
+
+/// Séries d'une page du catalogue
+pub fn parse_manga_list(html: Document, page: i32) -> Result<MangaPageResult> {
 	let mut mangas: Vec<Manga> = Vec::new();
 	
 	// Sélecteur pour les éléments manga dans le catalogue
@@ -265,6 +174,8 @@ pub fn parse_manga_list(html: Document) -> Result<MangaPageResult> {
 				let cover_url = element.select("img").and_then(|els| els.first()).and_then(|el| el.attr("src")).unwrap_or_default();
 
 				let clean_url = helper::clean_url(&relative_url);
+
+				let genres = parse_card_genres(&element);
 				
 				mangas.push(Manga {
 					key: clean_url.clone(),
@@ -278,7 +189,7 @@ pub fn parse_manga_list(html: Document) -> Result<MangaPageResult> {
 					} else {
 						format!("{}{}", BASE_URL, clean_url)
 					}),
-					tags: Some(Vec::new()),
+					tags: Some(genres),
 					status: MangaStatus::Unknown,
 					content_rating: ContentRating::Safe,
 					viewer: Viewer::RightToLeft,
@@ -288,56 +199,66 @@ pub fn parse_manga_list(html: Document) -> Result<MangaPageResult> {
 		}
 	}
 	
-	// Simple pagination check
-	let has_next_page = html.select(".pagination").is_some();
-	
 	Ok(MangaPageResult {
 		entries: mangas,
-		has_next_page
+		has_next_page: has_next_catalogue_page(&html, page),
 	})
 }
 
-pub fn parse_manga_listing(html: Document, listing_type: &str) -> Result<MangaPageResult> {
+pub fn parse_manga_listing(html: Document, listing_type: &str, page: i32) -> Result<MangaPageResult> {
 	let mut mangas: Vec<Manga> = Vec::new();
 	
 	if listing_type == "Dernières Sorties" {
-		// Pour les dernières sorties, chercher dans le conteneur d'accueil
-		if let Some(container) = html.select("#containerAjoutsScans > div, .latest-manga > div, .home-manga > div") {
+		// Seul le bloc des scans de l'accueil : les épisodes d'anime sont dans d'autres blocs
+		if let Some(container) = html.select("#containerAjoutsScans > div") {
 			for element in container {
-				if let Some(title) = element.select("h1, h2, h3, .title, .manga-title").and_then(|els| els.text()) {
-					if title.is_empty() {
-						continue;
-					}
-					
-					let relative_url = element.select("a").and_then(|els| els.first()).and_then(|el| el.attr("href")).unwrap_or_default();
-					let cover_url = element.select("img").and_then(|els| els.first()).and_then(|el| el.attr("src")).unwrap_or_default();
-
-					let clean_url = helper::clean_url(&relative_url);
-					
-					mangas.push(Manga {
-						key: clean_url.clone(),
-						cover: if !cover_url.is_empty() { Some(cover_url) } else { None },
-						title,
-						authors: None,
-						artists: None,
-						description: None,
-						url: Some(if clean_url.starts_with("http") {
-							clean_url.clone()
-						} else {
-							format!("{}{}", BASE_URL, clean_url)
-						}),
-						tags: Some(Vec::new()),
-						status: MangaStatus::Unknown,
-						content_rating: ContentRating::Safe,
-						viewer: Viewer::RightToLeft,
-						..Default::default()
-					});
+				let relative_url = element.select("a").and_then(|els| els.first()).and_then(|el| el.attr("href")).unwrap_or_default();
+				if !relative_url.contains("/scan") {
+					continue;
 				}
+				let Some(title) = element.select("h1, h2, h3, .title, .manga-title").and_then(|els| els.text()) else {
+					continue;
+				};
+				if title.is_empty() {
+					continue;
+				}
+
+				let clean_url = helper::clean_url(&relative_url);
+				// Une série sortant plusieurs chapitres n'apparaît qu'une fois, avec le plus récent
+				if mangas.iter().any(|manga| manga.key == clean_url) {
+					continue;
+				}
+				let cover_url = element.select("img").and_then(|els| els.first()).and_then(|el| el.attr("src")).unwrap_or_default();
+				let release = element.text().as_deref().and_then(extract_release_label);
+				
+				mangas.push(Manga {
+					key: clean_url.clone(),
+					cover: if !cover_url.is_empty() { Some(cover_url) } else { None },
+					title,
+					authors: None,
+					artists: None,
+					description: release,
+					url: Some(if clean_url.starts_with("http") {
+						clean_url.clone()
+					} else {
+						format!("{}{}", BASE_URL, clean_url)
+					}),
+					tags: Some(Vec::new()),
+					status: MangaStatus::Unknown,
+					content_rating: ContentRating::Safe,
+					viewer: Viewer::RightToLeft,
+					..Default::default()
+				});
 			}
 		}
+
+		return Ok(MangaPageResult {
+			entries: mangas,
+			has_next_page: has_next_catalogue_page(&html, page),
+		});
 	} else if listing_type == "Populaire" {
 		// Pour populaire, utiliser le catalogue normal
-		return parse_manga_list(html);
+		return parse_manga_list(html, page);
 	}
 	
 	Ok(MangaPageResult {
@@ -346,152 +267,84 @@ pub fn parse_manga_listing(html: Document, listing_type: &str) -> Result<MangaPa
 	})
 }
 
-pub fn parse_manga_details(manga_key: String, html: Document) -> Result<Manga> {
-	let title = extract_title_from_html(&html, &manga_key);
-	
-	// Extraire la description - utiliser le sélecteur exact de l'ancienne version
-	let description = {
-		// Méthode 1: Sélecteur direct comme l'ancienne version
-		if let Some(synopsis_p) = html.select("#sousBlocMiddle h2:contains(Synopsis) + p") {
-			if let Some(first_p) = synopsis_p.first() {
-				if let Some(desc_text) = first_p.text() {
-					let trimmed = desc_text.trim();
-					if !trimmed.is_empty() && trimmed.len() > 10 {
-						trimmed.to_string()
-					} else {
-						"Manga disponible sur AnimeSama".to_string()
-					}
-				} else {
-					"Manga disponible sur AnimeSama".to_string()
-				}
-			} else {
-				"Manga disponible sur AnimeSama".to_string()
-			}
-		} else {
-			// Fallback: chercher manuellement dans tout le sousBlocMiddle
-			if let Some(full_text) = html.select("#sousBlocMiddle").and_then(|els| els.text()) {
-				// Chercher après le mot "Synopsis" dans le texte complet
-				if let Some(synopsis_pos) = full_text.to_uppercase().find("SYNOPSIS") {
-					let after_synopsis = &full_text[synopsis_pos + 8..]; // Skip "SYNOPSIS"
-					// Prendre les prochaines lignes jusqu'à GENRES ou autre section
-					if let Some(end_pos) = after_synopsis.to_uppercase().find("GENRES") {
-						let desc_section = &after_synopsis[..end_pos];
-						let cleaned = desc_section.trim();
-						if !cleaned.is_empty() && cleaned.len() > 20 {
-							cleaned.to_string()
-						} else {
-							"Manga disponible sur AnimeSama".to_string()
-						}
-					} else {
-						// Prendre les premiers 500 caractères après Synopsis
-						let desc_section = if after_synopsis.len() > 500 {
-							&after_synopsis[..500]
-						} else {
-							after_synopsis
-						};
-						let cleaned = desc_section.trim();
-						if !cleaned.is_empty() && cleaned.len() > 20 {
-							cleaned.to_string()
-						} else {
-							"Manga disponible sur AnimeSama".to_string()
-						}
-					}
-				} else {
-					"Manga disponible sur AnimeSama".to_string()
-				}
-			} else {
-				"Manga disponible sur AnimeSama".to_string()
-			}
-		}
-	};
-	
-	// Parser la couverture avec fallback
-	let cover = html.select("#coverOeuvre")
-		.and_then(|els| els.first())
-		.and_then(|el| el.attr("src"))
+/// URL de la bannière : image, ou `background-image` d'un bloc de la page
+fn extract_banner(html: &Document) -> Option<String> {
+	let element = html.select("#bannerOeuvre, #banniereOeuvre, #imgBanniere, .banner").and_then(|els| els.first())?;
+	element
+		.attr("src")
+		.or_else(|| element.select("img").and_then(|els| els.first()).and_then(|img| img.attr("src")))
 		.or_else(|| {
-			// Fallback: chercher d'autres sélecteurs d'image
-			let selectors = vec!["img.cover", ".manga-cover img", ".poster img", "img[src*='cover']"];
-			for selector in selectors {
-				if let Some(src) = html.select(selector).and_then(|els| els.first()).and_then(|el| el.attr("src")) {
-					return Some(src);
-				}
-			}
-			None
+			let style = element.attr("style")?;
+			let start = style.find("url(")? + "url(".len();
+			let end = style[start..].find(')')?;
+			Some(style[start..start + end].trim_matches(['\'', '"', ' ']).to_string())
 		})
-		.map(|src| {
-			if src.starts_with("http") {
-				src
-			} else {
-				format!("{}{}", BASE_URL, src)
-			}
-		});
-	
-	// Extraire les genres - logique exacte de l'ancienne version adaptée
-	let mut tags: Vec<String> = Vec::new();
-	
-	// Méthode 1: Essayer de récupérer les liens individuellement après h2:contains(Genres) - exactement comme l'ancienne version
-	if let Some(genre_elements) = html.select("#sousBlocMiddle h2:contains(Genres) + a") {
-		for genre_elem in genre_elements {
-			if let Some(genre_text) = genre_elem.text() {
-				let genre_raw = genre_text.trim();
-				if !genre_raw.is_empty() {
-					// Vérifier si ce genre contient des séparateurs (virgules ou tirets)
-					if genre_raw.contains(',') || genre_raw.contains(" - ") {
-						// Diviser par les virgules ou tirets et ajouter chaque genre individuellement
-						let separator = if genre_raw.contains(',') { "," } else { " - " };
-						for genre in genre_raw.split(separator) {
-							let cleaned_genre = genre.trim();
-							if !cleaned_genre.is_empty() {
-								tags.push(cleaned_genre.to_string());
-							}
-						}
-					} else {
-						// Genre unique, l'ajouter directement
-						tags.push(genre_raw.to_string());
-					}
-				}
-			}
-		}
+		.filter(|url| !url.is_empty())
+}
+
+fn absolute_url(url: String) -> String {
+	if url.starts_with("http") {
+		url
+	} else if url.starts_with("//") {
+		format!("https:{}", url)
+	} else {
+		format!("{}{}", BASE_URL, url)
 	}
-	
-	// Méthode 2: Si pas de genres trouvés, essayer de récupérer le texte complet des genres - exactement comme l'ancienne version
-	if tags.is_empty() {
-		// Chercher le texte après le h2 "Genres" qui peut contenir "Action, Comédie, Horreur, Science-fiction"
-		if let Some(genres_text) = html.select("#sousBlocMiddle").and_then(|el| el.text()) {
-			// Chercher la section GENRES dans le texte
-			if let Some(genres_start) = genres_text.find("GENRES") {
-				let genres_section = &genres_text[genres_start..];
-				
-				// Prendre la première ligne après "GENRES" qui contient les genres séparés par des virgules
-				if let Some(first_line_end) = genres_section.find('\n') {
-					let genres_line = &genres_section[7..first_line_end].trim(); // Skip "GENRES\n"
-					
-					// Diviser par les virgules ou tirets et nettoyer chaque genre
-					let separator = if genres_line.contains(',') { "," } else { " - " };
-					for genre in genres_line.split(separator) {
-						let cleaned_genre = genre.trim();
-						if !cleaned_genre.is_empty() {
-							tags.push(cleaned_genre.to_string());
-						}
+}
+
+/// Genres de la page d'une série : les liens qui suivent le titre « Genres »
+/// jusqu'au titre suivant, « Action, Comédie » dans un seul lien ou un lien par genre
+fn parse_genres(html: &Document) -> Vec<String> {
+	let mut tags: Vec<String> = Vec::new();
+	let mut next = html
+		.select("#sousBlocMiddle h2:contains(Genres)")
+		.and_then(|headings| headings.first())
+		.and_then(|heading| heading.next_element_sibling());
+
+	while let Some(element) = next {
+		match element.tag_name().as_deref() {
+			Some("h2") => break,
+			Some("a") => {
+				let text = element.text().unwrap_or_default();
+				for genre in text.split(',').flat_map(|part| part.split(" - ")).map(str::trim) {
+					if !genre.is_empty() && !tags.iter().any(|tag| tag == genre) {
+						tags.push(genre.to_string());
 					}
 				}
 			}
+			_ => {}
 		}
+		next = element.next_element_sibling();
 	}
-	
-	// Ajouter "Manga" par défaut si aucun genre trouvé
-	if tags.is_empty() {
-		tags.push("Manga".to_string());
-	}
-	
-	// Status: always Ongoing as site doesn't provide status info
-	let status = MangaStatus::Ongoing;
 
-	// Calculate content_rating based on tags
-	let content_rating = calculate_content_rating(&tags);
+	tags
+}
+
+/// Détails de la série depuis sa page : couverture (la bannière à défaut), titres
+/// alternatifs et synopsis dans la description, genres. Le statut n'y figure pas,
+/// il se lit dans la liste des chapitres avec [`parse_scan_status`].
+pub fn parse_manga_details(manga_key: String, html: &Document) -> Result<Manga> {
+	let title = extract_title_from_html(html).unwrap_or_else(|| extract_cdn_title(html, &manga_key));
+
+	let synopsis = select_text(html, "#sousBlocMiddle h2:contains(Synopsis) + p");
+	let alternative_titles = select_text(html, "#titreAlter").filter(|titles| *titles != title);
+	let description = match (alternative_titles, synopsis) {
+		(Some(titles), Some(synopsis)) => Some(format!("Titres alternatifs : {}\n\n{}", titles, synopsis)),
+		(Some(titles), None) => Some(format!("Titres alternatifs : {}", titles)),
+		(None, synopsis) => synopsis,
+	};
 
-	// Calculate viewer based on tags (Manhwa/Webtoon vs Manga)
+	let cover = html
+		.select("#coverOeuvre")
+		.and_then(|els| els.first())
+		.and_then(|el| el.attr("src"))
+		.filter(|src| !src.is_empty())
+		.or_else(|| extract_banner(html))
+		.map(absolute_url);
+
+	let tags = parse_genres(html);
+
+	let content_rating = calculate_content_rating(&tags);
 	let viewer = calculate_viewer(&tags);
 
 	Ok(Manga {
@@ -499,7 +352,7 @@ pub fn parse_manga_details(manga_key: String, html: Document) -> Result<Manga> {
 		title,
 		authors: None,
 		artists: None,
-		description: Some(description),
+		description,
 		url: Some(if manga_key.starts_with("http") {
 			manga_key.clone()
 		} else {
@@ -507,749 +360,429 @@ pub fn parse_manga_details(manga_key: String, html: Document) -> Result<Manga> {
 		}),
 		cover,
 		tags: Some(tags),
-		status,
+		status: MangaStatus::Unknown,
 		content_rating,
 		viewer,
 		..Default::default()
 	})
 }
 
-pub fn parse_chapter_list(manga_key: String, html: Document) -> Result<Vec<Chapter>> {
-	let mut chapters: Vec<Chapter> = Vec::new();
+/// Statut d'une série d'après les marqueurs de la liste des chapitres d'une piste
+pub fn parse_scan_status(html: &Document) -> MangaStatus {
+	episodes::parse_status(&inline_scripts(html))
+}
 
-	let manga_name = extract_title_from_html(&html, &manga_key);
-		
-	// Parse JavaScript content for chapter mappings
-	// IMPORTANT: Récupérer TOUT le contenu de la page, y compris les scripts inline
-	let mut html_content = String::new();
-	
-	// Récupérer le contenu des scripts
+/// Piste de scans d'une série, déclarée sur sa page par
+/// `panneauScan("Scans (Noir et Blanc)", "scan_noir-et-blanc/vf");`
+#[derive(Debug, Clone)]
+pub struct ScanVariant {
+	pub name: String,
+	pub path: String,
+}
+
+/// Pistes de scans déclarées par les appels `panneauScan` de la page d'une série,
+/// hors appels commentés et exemples du site (`panneauScan("nom", "url")`)
+pub fn parse_scan_variants(html: &Document) -> Vec<ScanVariant> {
+	let mut variants: Vec<ScanVariant> = Vec::new();
+
+	let Some(scripts) = html.select("script") else {
+		return variants;
+	};
+	for script in scripts {
+		let Some(content) = script.data() else {
+			continue;
+		};
+
+		let mut search_start = 0;
+		while let Some(pos) = content[search_start..].find("panneauScan(") {
+			let call_start = search_start + pos;
+			let args_start = call_start + "panneauScan(".len();
+			let Some(args_end) = content[args_start..].find(')').map(|end| args_start + end) else {
+				break;
+			};
+			search_start = args_end;
+
+			let line_start = content[..call_start].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+			let in_line_comment = content[line_start..call_start].contains("//");
+			let in_block_comment = content[..call_start].rfind("/*") > content[..call_start].rfind("*/");
+			if in_line_comment || in_block_comment {
+				continue;
+			}
+
+			let args: Vec<&str> = content[args_start..args_end]
+				.split(['"', '\''])
+				.skip(1)
+				.step_by(2)
+				.collect();
+			let &[name, path] = args.as_slice() else {
+				continue;
+			};
+			let path = path.trim().trim_matches('/');
+			if path.is_empty() || path == "url" || variants.iter().any(|variant| variant.path == path) {
+				continue;
+			}
+
+			variants.push(ScanVariant {
+				name: name.trim().to_string(),
+				path: path.to_string(),
+			});
+		}
+	}
+
+	variants
+}
+
+/// JavaScript d'une page de piste : scripts inline, attributs d'événements et
+/// fichier `episodes.js` qui porte ses tableaux `epsN`
+fn track_scripts(html: &Document, page_url: &str) -> String {
+	let mut content = String::new();
 	if let Some(scripts) = html.select("script") {
 		for script in scripts {
-			if let Some(script_text) = script.text() {
-				html_content.push_str(&script_text);
-				html_content.push('\n');
+			if let Some(script_text) = script.data() {
+				content.push_str(&script_text);
+				content.push('\n');
+			}
+			let src = script.attr("src").unwrap_or_default();
+			if src.contains("episodes.js") {
+				if let Ok(episodes_js) = Request::get(absolute_script_url(&src, page_url))
+					.and_then(|request| request.header("Referer", BASE_URL).string())
+				{
+					content.push_str(&episodes_js);
+					content.push('\n');
+				}
 			}
 		}
 	}
-	
-	// Récupérer aussi le HTML brut complet pour chercher dans les commentaires et autres endroits
 	if let Some(body) = html.select("body") {
 		for element in body {
-			// Récupérer le HTML complet de l'élément (peut contenir du JS dans les commentaires)
-			if let Some(full_html) = element.html() {
-				html_content.push_str(&full_html);
-				html_content.push('\n');
-			}
-			
-			// Chercher dans tous les attributs qui pourraient contenir du JavaScript
-			for attr_name in ["onclick", "onload", "data-script", "data-js", "data-chapters"] {
+			for attr_name in ["onclick", "onload", "data-script", "data-js"] {
 				if let Some(attr_value) = element.attr(attr_name) {
-					html_content.push_str(&attr_value);
-					html_content.push('\n');
-				}
-			}
-		}
-	}
-	
-	// Chercher aussi dans les commentaires HTML de toute la page
-	if let Some(head) = html.select("head") {
-		for element in head {
-			if let Some(head_html) = element.html() {
-				html_content.push_str(&head_html);
-				html_content.push('\n');
-			}
-		}
-	}
-	
-	// Parse JavaScript commands to create chapter mappings
-	let (mut chapter_mappings, finir_liste_info) = parse_chapter_mapping(&html_content);
-	
-	// Si aucun mapping JavaScript trouvé, essayer une détection basique de chapitres spéciaux
-	if chapter_mappings.is_empty() && finir_liste_info.is_none() {
-		// Cas spécial : One Piece sans JavaScript détecté - ajouter le One Shot manuellement
-		if helper::is_one_piece_manga(&manga_key) {
-			// Vérifier si on a des chapitres > 1045 avant d'ajouter le One Shot
-			let max_cdn_chapter = get_max_available_chapter_on_cdn(&manga_name);
-			
-			if max_cdn_chapter > 1045 {
-				// Ajouter le One Shot uniquement si le CDN a des chapitres après 1045
-				chapter_mappings.push(ChapterMapping {
-					index: 1046, // Position entre 1045 et 1046
-					chapter_number: 1045.5, // Numéro pour tri correct
-					title: "Chapitre One Shot".to_string(),
-				});
-			}
-		}
-		
-		// Chercher des chapitres décimaux de manière plus intelligente
-		for line in html_content.lines() {
-			if let Some(decimal_chapter) = find_decimal_chapter_in_line(line) {
-				// Vérifier que c'est un chapitre plausible (entre 1 et 100, avec .5 uniquement)
-				if decimal_chapter >= 1.0 && decimal_chapter <= 100.0 {
-					let fractional_part = decimal_chapter - (decimal_chapter as i32 as f32);
-					// Accepter seulement exactement .5 (cela élimine automatiquement .507, .498, etc.)
-					if (fractional_part - 0.5).abs() < 0.01 {
-						// Vérifier que c'est dans un contexte de chapitre (pas forcément même ligne)
-						let is_chapter_context = line.contains("Chapitre") || 
-							line.contains("Chapter") ||
-							line.contains("chapitre") ||
-							line.contains("eps") ||
-							line.contains("Ep");
-						
-						if is_chapter_context {
-							// Éviter les doublons
-							let exists = chapter_mappings.iter().any(|m| 
-								(m.chapter_number - decimal_chapter).abs() < 0.01
-							);
-							
-							if !exists {
-								let base_chapter = decimal_chapter as i32;
-								let correct_index = base_chapter + 1;
-								chapter_mappings.push(ChapterMapping {
-									index: correct_index,
-									chapter_number: decimal_chapter,
-									title: format!("Chapitre {}", decimal_chapter),
-								});
-							}
-						}
-					}
+					content.push_str(&attr_value);
+					content.push('\n');
 				}
 			}
 		}
 	}
-	
-	// Get total chapters from API (this is the highest chapter NUMBER, not index count)
-	let api_max_chapter = match get_total_chapters_from_api(&manga_name) {
-		Ok(count) => {
-			// Limiter pour certains mangas où l'API retourne des nombres incorrects
-			if manga_name.to_lowercase().contains("versatile mage") || manga_key.contains("versatile-mage") {
-				// Pour Versatile Mage, utiliser un maximum très élevé pour couvrir tous les chapitres
-				// Basé sur les tests, il y a des chapitres jusqu'à 817, 870, etc.
-				count.min(1000) // Limite très élevée pour couvrir tous les chapitres possibles
-			} else {
-				count.min(2000) // Limite élevée pour les autres mangas aussi
-			}
-		},
-		Err(_) => {
-			// Fallback: très élevé pour couvrir tous les chapitres possibles
-			if manga_name.to_lowercase().contains("versatile mage") || manga_key.contains("versatile-mage") {
-				// Pour Versatile Mage, utiliser un fallback très élevé pour couvrir tous les chapitres
-				800 // Très élevé pour couvrir 817, 870+ et plus
-			} else if !chapter_mappings.is_empty() {
-				chapter_mappings.iter().map(|m| m.index).max().unwrap_or(500) + 200
-			} else {
-				500 // Fallback très généreux pour tous les mangas
-			}
-		}
-	};
-	
-	// Recherche élargie pour les One Shot et chapitres spéciaux (maintenant qu'on a api_max_chapter)
-	for line in html_content.lines() {
-		// Chercher plusieurs patterns pour les chapitres spéciaux
-		let special_patterns = [
-			"One Shot", "OneShot", "one shot", "ONE SHOT",
-			"Hors-série", "Hors série", "hors-série", "hors série",
-			"Spécial", "Special", "spécial", "special",
-			"Prologue", "Epilogue", "Extra"
-		];
-		
-		for pattern in &special_patterns {
-			// Vérifier si c'est vraiment un chapitre (doit contenir "Chapitre" ou "Chapter")
-			if line.contains(pattern) && (line.contains("Chapitre") || line.contains("Chapter")) {
-				// Déterminer la position correcte selon le manga et le type de chapitre spécial
-				let (title, index, chapter_number) = if pattern.contains("One Shot") || pattern.contains("OneShot") {
-					if helper::is_one_piece_manga(&manga_key) {
-						// One Piece: One Shot entre chapitres 1045 et 1046
-						("Chapitre One Shot".to_string(), 1046, 1045.5)
-					} else {
-						// Autres mangas: essayer de le placer vers la fin, mais pas tout à la fin
-						let position = (api_max_chapter * 3 / 4).max(1); // 3/4 de la série
-						("Chapitre One Shot".to_string(), position, position as f32 - 0.5)
-					}
-				} else if pattern.contains("Hors") {
-					let position = (api_max_chapter * 2 / 3).max(1); // Vers 2/3 de la série
-					("Chapitre Hors-série".to_string(), position, position as f32 - 0.3)
-				} else if pattern.contains("Spécial") || pattern.contains("Special") {
-					let position = (api_max_chapter * 4 / 5).max(1); // Vers 4/5 de la série
-					("Chapitre Spécial".to_string(), position, position as f32 - 0.2)
-				} else {
-					let position = api_max_chapter / 2; // Milieu pour les autres
-					(format!("Chapitre {}", pattern), position, position as f32 - 0.4)
-				};
-				
-				chapter_mappings.push(ChapterMapping {
-					index,
-					chapter_number,
-					title,
-				});
-				break; // Sortir de la boucle des patterns pour cette ligne
-			}
-		}
-	}
+	content
+}
 
-	// Create chapters: first all normal chapters, then insert special chapters at correct positions
-	
-	// Step 1: Create all normal chapters (1, 2, 3, ..., api_max_chapter)
-	for chapter_num in 1..=api_max_chapter {
-		let chapter_key = chapter_num.to_string();
-		chapters.push(Chapter {
-			key: chapter_key,
-			title: Some(format!("Chapitre {}", chapter_num)),
-			chapter_number: Some(chapter_num as f32),
-			volume_number: None,
-			date_uploaded: None,
-			scanlators: Some(vec![]),
-			url: Some(build_chapter_url(&manga_key)),
-			..Default::default()
-		});
+/// Nombre de positions d'une piste : le plus grand `epsN` qu'elle publie. L'API ne
+/// compte que les chapitres de la série sur le CDN, elle ne sert que pour la
+/// piste historique quand la piste ne publie aucun `epsN`.
+fn track_total(scripts: &str, manga_title: &str, is_legacy_track: bool) -> i32 {
+	let eps_total = scripts
+		.match_indices("eps")
+		.filter_map(|(pos, _)| {
+			// `eps12 =` ou `eps12.length`, pas `episodes` ni `eps12abc`
+			if scripts[..pos].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+				return None;
+			}
+			let rest = &scripts[pos + "eps".len()..];
+			let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+			let after = rest[end..].trim_start();
+			if !(after.starts_with('=') || after.starts_with(".length")) {
+				return None;
+			}
+			rest[..end].parse::<i32>().ok()
+		})
+		.max();
+
+	match eps_total {
+		Some(total) => total,
+		None if is_legacy_track => get_total_chapters_from_api(manga_title).unwrap_or(0),
+		None => 0,
 	}
-	
-	// Step 2: Add all special chapters with their correct chapter numbers
-	for mapping in &chapter_mappings {
-		let chapter_key = if mapping.title.contains("One Shot") {
-			"9999".to_string() // Safe key that won't conflict with regular chapters
-		} else {
-			// For decimal chapters like 19.5, use a key that won't conflict
-			format!("{}_{}", (mapping.chapter_number as i32), 
-				((mapping.chapter_number - (mapping.chapter_number as i32 as f32)) * 10.0) as i32)
-		};
-		
-		
+}
+
+pub fn parse_chapter_list(manga_key: String, variant: &ScanVariant, html: Document) -> Result<Vec<Chapter>> {
+	let mut chapters: Vec<Chapter> = Vec::new();
+
+	let chapter_url = helper::scan_track_url(BASE_URL, &manga_key, &variant.path);
+	let manga_name = extract_cdn_title(&html, &manga_key);
+
+	// La liste est décrite par les scripts de la page de la piste
+	let scripts = track_scripts(&html, &chapter_url);
+	let is_legacy_track = variant.path == helper::legacy_scan_path(&manga_key);
+	let episodes = episodes::parse_episodes(&scripts, track_total(&scripts, &manga_name, is_legacy_track));
+
+	let scanlators = vec![variant.name.clone()];
+
+	for episode in episodes {
 		chapters.push(Chapter {
-			key: chapter_key,
-			title: Some(mapping.title.clone()),
-			chapter_number: Some(mapping.chapter_number),
+			key: helper::chapter_key(&manga_key, &variant.path, &episode.key()),
+			title: Some(episode.title),
+			chapter_number: episode.number,
 			volume_number: None,
 			date_uploaded: None,
-			scanlators: Some(vec![]),
-			url: Some(build_chapter_url(&manga_key)),
+			scanlators: Some(scanlators.clone()),
+			url: Some(chapter_url.clone()),
 			..Default::default()
 		});
 	}
-	
-	// Tri par numéro de chapitre (du plus récent au plus ancien)
-	// Exactement comme dans l'ancienne version: b.chapter > a.chapter
-	chapters.sort_by(|a, b| {
-		let a_num = a.chapter_number.unwrap_or(0.0);
-		let b_num = b.chapter_number.unwrap_or(0.0);
-		// Plus simple: utiliser la comparaison directe comme l'ancienne version
-		b_num.partial_cmp(&a_num).unwrap_or(core::cmp::Ordering::Equal)
-	});
-	
+
+	// Du plus récent au plus ancien
+	chapters.reverse();
+
 	Ok(chapters)
 }
 
-pub fn parse_page_list(html: Document, manga_key: String, chapter_key: String) -> Result<Vec<Page>> {
-	let mut pages: Vec<Page> = Vec::new();
-	
-	
-	// Récupérer TOUT le contenu HTML pour chercher les variables eps
-	// Les variables JavaScript peuvent être inline ou dans différents endroits
-	let mut html_content = String::new();
-	
-	// Récupérer le contenu des scripts
-	if let Some(scripts) = html.select("script") {
-		for script in scripts {
-			if let Some(script_text) = script.text() {
-				html_content.push_str(&script_text);
-				html_content.push('\n');
-			}
-		}
+pub fn parse_page_list(html: Document, manga_key: String, chapter_key: String, chapter_url: &str) -> Result<Vec<Page>> {
+	// Récupérer le JavaScript du lecteur : scripts inline, attributs et episodes.js
+	let html_content = track_scripts(&html, chapter_url);
+	let manga_title = extract_cdn_title(&html, &manga_key);
+
+	// La clé du chapitre est son numéro ; son dossier sur le CDN est sa position
+	// dans la liste de la piste, construite comme pour la liste des chapitres
+	let (path, number_key) = helper::split_chapter_key(&manga_key, &chapter_key);
+	let is_legacy_track = path == helper::legacy_scan_path(&manga_key);
+	let total = track_total(&html_content, &manga_title, is_legacy_track);
+	let chapter_index = episodes::find_index(&episodes::parse_episodes(&html_content, total), number_key)
+		.ok_or_else(|| AidokuError::message("Chapitre inconnu, actualisez la liste des chapitres"))?;
+
+	// Tableau epsN du lecteur : URLs directes, ou liens Google Drive si le CDN n'a pas le chapitre
+	let eps_urls = find_eps_array(&html_content, chapter_index)
+		.and_then(parse_eps_array)
+		.unwrap_or_default();
+	let reader_urls = cdn::reader_image_urls(&eps_urls);
+	if !reader_urls.is_empty() {
+		return Ok(urls_to_pages(reader_urls));
 	}
-	
-	// Récupérer aussi le JavaScript inline dans les attributs
-	if let Some(body) = html.select("body") {
-		for element in body {
-			for attr_name in ["onclick", "onload", "data-script", "data-js"] {
-				if let Some(attr_value) = element.attr(attr_name) {
-					html_content.push_str(&attr_value);
-					html_content.push('\n');
-				}
+	let google_drive_urls: Vec<String> = eps_urls
+		.iter()
+		.filter(|url| drive::is_drive_url(url))
+		.cloned()
+		.collect();
+
+	// Nombre exact de pages : tableau epsN, `epsN.length = n`, puis l'API
+	let page_count = Some(eps_urls.len() as i32)
+		.filter(|count| *count > 0)
+		.or_else(|| find_eps_length(&html_content, chapter_index))
+		.or_else(|| {
+			fetch_page_counts(&manga_title)
+				.ok()
+				.and_then(|counts| counts.get(&chapter_index).copied())
+		})
+		.ok_or_else(|| AidokuError::message("Aucune page trouvée pour ce chapitre"))?;
+
+	// Chapitre absent du CDN : pages hébergées sur Google Drive
+	match cdn::resolve_image_urls(&manga_title, chapter_index, page_count) {
+		Ok(urls) => Ok(urls_to_pages(urls)),
+		Err(e) => {
+			let drive_urls = drive::image_urls(&google_drive_urls);
+			if drive_urls.is_empty() {
+				Err(e)
+			} else {
+				Ok(urls_to_pages(drive_urls))
 			}
 		}
 	}
-	
-	// Méthode 1: Chercher les variables d'épisode JavaScript (ex: eps1, eps2)
-	// Special handling for One Shot chapter
-	let mut episode_patterns = Vec::new();
-	let fallback_chapter = if chapter_key == "9999" {
-		// One Shot: essayer plusieurs patterns possibles
-		episode_patterns.push("eps1045".to_string()); // Peut être stocké comme 1045
-		episode_patterns.push("epsOneShot".to_string()); // Peut être nommé OneShot
-		episode_patterns.push("epsOS".to_string()); // Abbreviation possible
-		episode_patterns.push("eps0".to_string()); // Peut être à l'index 0
-		1045 // Fallback vers 1045
-	} else {
-		episode_patterns.push(format!("eps{}", chapter_key));
-		chapter_key.parse::<i32>().unwrap_or(1)
-	};
-	
-	// Essayer tous les patterns jusqu'à en trouver un qui fonctionne
-	let mut found_episode_section = None;
-	for pattern in &episode_patterns {
-		if let Some(episode_start) = html_content.find(pattern) {
-			found_episode_section = Some((episode_start, pattern.clone()));
-			break;
-		}
-	}
-	
-	// Stocker les informations JavaScript pour fallback plus tard
-	let mut google_drive_pages: Vec<Page> = Vec::new();
-	if let Some((episode_start, _pattern)) = found_episode_section {
-		// Trouver la fin de la déclaration de variable
-		if let Some(episode_section) = html_content[episode_start..].find('[') {
-			let array_start = episode_start + episode_section;
-			if let Some(array_end) = html_content[array_start..].find("];") {
-				let array_content = &html_content[array_start + 1..array_start + array_end];
-				
-				// Parser les URLs dans le tableau JavaScript (pour fallback)
-				for line in array_content.lines() {
-					let trimmed = line.trim();
-					if trimmed.starts_with("'https://drive.google.com") || trimmed.starts_with("\"https://drive.google.com") {
-						let url_start = trimmed.find("https://").unwrap_or(0);
-						let url_end = if trimmed[url_start..].contains('\'') {
-							url_start + trimmed[url_start..].find('\'').unwrap_or(trimmed.len())
-						} else {
-							url_start + trimmed[url_start..].find('"').unwrap_or(trimmed.len())
-						};
-						
-						if url_end > url_start {
-							let drive_url = &trimmed[url_start..url_end];
-							google_drive_pages.push(Page {
-								content: PageContent::url(drive_url.to_string()),
-								thumbnail: None,
-								has_description: false,
-								description: None,
-							});
-						}
-					}
-				}
-			}
+}
+
+/// Contenu du tableau `epsN = [...]` du chapitre à la position `chapter_index`
+fn find_eps_array(html_content: &str, chapter_index: i32) -> Option<&str> {
+	let pattern = format!("eps{}", chapter_index);
+	let mut search_start = 0;
+	while let Some(pos) = html_content[search_start..].find(&pattern) {
+		let after = search_start + pos + pattern.len();
+		search_start = after;
+		// eps1 ne doit pas correspondre à eps12
+		let rest = html_content[after..].trim_start();
+		if let Some(array) = rest.strip_prefix('=').map(str::trim_start).and_then(|rest| rest.strip_prefix('[')) {
+			return array.find(']').map(|end| &array[..end]);
 		}
 	}
-	
-	// PRIORITÉ 1 : Utiliser le CDN par défaut (plus fiable)
-	let chapter_index = fallback_chapter;
-	
-	// Extraire le nom du manga depuis l'ID (ex: /catalogue/blue-lock -> blue-lock)
-	let manga_slug = manga_key.split('/').last().unwrap_or("manga");
-		
-		// Extraire le titre du manga depuis le HTML pour construire les URLs CDN
-		let mut manga_title = String::new();
-		
-		// Méthode 1: Chercher #titreOeuvre (page principale manga)
-		if let Some(title_elem) = html.select("#titreOeuvre").and_then(|els| els.first()) {
-			if let Some(title_text) = title_elem.text() {
-				let cleaned_title = clean_extracted_title(title_text.trim());
-				if !cleaned_title.is_empty() {
-					manga_title = cleaned_title;
-				}
-			}
-		}
-		
-		// Méthode 2: Extraire depuis le <title> de la page (ex: "Kaiju N°8 - Scans")
-		if manga_title.is_empty() {
-			if let Some(title_elem) = html.select("title").and_then(|els| els.first()) {
-				if let Some(page_title) = title_elem.text() {
-					let page_title = page_title.trim();
-					if !page_title.is_empty() && page_title.contains(" - ") {
-						// Extraire la partie avant " - Scans" ou " - "
-						let extracted = page_title.split(" - ").next().unwrap_or("").trim();
-						let cleaned_title = clean_extracted_title(extracted);
-						if !cleaned_title.is_empty() {
-							manga_title = cleaned_title;
-						}
-					}
-				}
-			}
-		}
-		
-		// Méthode 3: Chercher dans les éléments h1 qui peuvent contenir le titre
-		if manga_title.is_empty() {
-			if let Some(h1_elem) = html.select("h1").and_then(|els| els.first()) {
-				if let Some(h1_text) = h1_elem.text() {
-					let cleaned_title = clean_extracted_title(h1_text.trim());
-					if !cleaned_title.is_empty() {
-						manga_title = cleaned_title;
-					}
-				}
-			}
-		}
-		
-		// Fallback final: utiliser manga_key_to_title avec gestion des cas spéciaux
-		if manga_title.is_empty() {
-			manga_title = manga_key_to_title(manga_slug);
-		}
-		
-		// PRIORITÉ 1 : Parser le JavaScript dans le HTML pour trouver les patterns eps{number}
-		let mut page_count = parse_episodes_js_from_html(&html_content, chapter_index);
-		
-		// Si c'est le One Shot et qu'on n'a pas trouvé de pages, essayer d'autres indices
-		if page_count == 0 && chapter_key == "9999" {
-			// Essayer différents indices pour le One Shot
-			for test_index in [1045, 1046, 0, 9999] {
-				page_count = parse_episodes_js_from_html(&html_content, test_index);
-				if page_count > 0 {
-					break;
-				}
-			}
-		}
-		
-		if page_count > 0 {
-			// Succès avec le parsing JavaScript - utiliser l'indice API dans l'URL
-			for i in 1..=page_count {
-				// Essayer différents formats d'images selon le manga
-				let page_url = generate_image_url(&manga_title, chapter_index, i);
-				pages.push(Page {
-					content: PageContent::url(page_url),
-					thumbnail: None,
-					has_description: false,
-					description: None,
-				});
-			}
-			return Ok(pages);
-		}
-		
-		// PRIORITÉ 2 : Fallback avec l'API AnimeSama  
-		match get_page_count_from_api(&manga_title, chapter_index) {
-			Ok(api_page_count) => {
-				for i in 1..=api_page_count {
-					let page_url = generate_image_url(&manga_title, chapter_index, i);
-					pages.push(Page {
-						content: PageContent::url(page_url),
-						thumbnail: None,
-						has_description: false,
-						description: None,
-					});
-				}
-				return Ok(pages);
-			}
-			Err(_) => {
-				// L'API a aussi échoué - pour Versatile Mage, essayer une approche différente
-				if manga_title == "Versatile Mage" && chapter_index >= 817 {
-					// Pour les chapitres récents de Versatile Mage, utiliser CDN directement
-					// avec une estimation conservative du nombre de pages
-					for i in 1..=30 {
-						let page_url = generate_image_url(&manga_title, chapter_index, i);
-						pages.push(Page {
-							content: PageContent::url(page_url),
-							thumbnail: None,
-							has_description: false,
-							description: None,
-						});
-					}
-					return Ok(pages);
-				}
-			}
-		}
-		
-		// PRIORITÉ 3 : Fallback CDN - nombre de pages par défaut selon le manga
-		let default_page_count = match manga_title.as_str() {
-			"Versatile Mage" => 25, // Versatile Mage a souvent plus de pages
-			_ => 20, // Fallback standard
-		};
-		
-		for i in 1..=default_page_count {
-			let page_url = generate_image_url(&manga_title, chapter_index, i);
-			
-			pages.push(Page {
-				content: PageContent::url(page_url),
-				thumbnail: None,
-				has_description: false,
-				description: None,
-			});
-		}
-		
-		// PRIORITÉ 4 : Google Drive en dernier recours absolu (si CDN échoue totalement)
-		if pages.is_empty() && !google_drive_pages.is_empty() {
-			return Ok(google_drive_pages);
-		}
-	
-	Ok(pages)
+	None
 }
 
-// Détecter le dernier chapitre disponible sur le CDN de manière conservatrice
-fn get_max_available_chapter_on_cdn(manga_title: &str) -> i32 {
-	// Pour One Piece, utiliser la limite complète connue
-	if manga_title == "One Piece" {
-		// One Piece a 1158 chapitres disponibles sur le CDN
-		1158
+fn absolute_script_url(src: &str, page_url: &str) -> String {
+	if src.starts_with("http") {
+		src.to_string()
+	} else if src.starts_with('/') {
+		format!("{}{}", BASE_URL, src)
 	} else {
-		// Pour les autres mangas, utiliser l'API avec une limite très élevée
-		match get_total_chapters_from_api(manga_title) {
-			Ok(count) => count.min(2000), // Limite très élevée pour couvrir tous les chapitres
-			Err(_) => 1000, // Fallback très généreux pour les autres mangas
-		}
+		let page_dir = page_url.split('?').next().unwrap_or(page_url);
+		let page_dir = &page_dir[..page_dir.rfind('/').map(|pos| pos + 1).unwrap_or(page_dir.len())];
+		format!("{}{}", page_dir, src)
 	}
 }
 
-// Fonction pour obtenir le nombre de pages depuis l'API AnimeSama
-fn get_page_count_from_api(manga_name: &str, chapter_num: i32) -> Result<i32> {
-	// Construire l'URL de l'API
-	let encoded_title = helper::urlencode(manga_name);
-	let api_url = format!("https://anime-sama.org/s2/scans/get_nb_chap_et_img.php?oeuvre={}", encoded_title);
-	
-	// Faire la requête ultra-simple
-	let json_string = Request::get(&api_url)?
-		.header("User-Agent", "curl/7.68.0")
-		.string()?;
-	
-	// Parser le JSON manuellement pour trouver le nombre de pages pour ce chapitre
-	let chapter_key = format!("\"{}\":", chapter_num);
-	if let Some(pos) = json_string.find(&chapter_key) {
-		let after_key = &json_string[pos + chapter_key.len()..];
-		// Chercher le nombre après les espaces/guillemets
-		let mut num_str = String::new();
-		for ch in after_key.trim().chars() {
-			if ch.is_ascii_digit() {
-				num_str.push(ch);
-			} else if !num_str.is_empty() {
-				break;
-			}
-		}
-		
-		if let Ok(page_count) = num_str.parse::<i32>() {
-			if page_count > 0 {
-				return Ok(page_count);
-			}
-		}
-	}
-	
-	Err(AidokuError::message("Chapter not found in API"))
+fn urls_to_pages(urls: Vec<String>) -> Vec<Page> {
+	urls.into_iter()
+		.map(|url| Page {
+			content: PageContent::url(url),
+			thumbnail: None,
+			has_description: false,
+			description: None,
+		})
+		.collect()
 }
 
-// Parser le JavaScript pour trouver eps{number}.length ou eps{number} = [...]
-fn parse_episodes_js_from_html(html_content: &str, chapter_num: i32) -> i32 {
-	// Regex inspirée de Tachiyomi : eps(\d+)\s*(?:=\s*\[(.*?)]|\.length\s*=\s*(\d+))
-	let mut start_pos = 0;
-	let eps_pattern = format!("eps{}", chapter_num);
-	
-	// Chercher le pattern eps{chapter_num} dans le HTML
-	while let Some(pos) = html_content[start_pos..].find(&eps_pattern) {
-		start_pos += pos + eps_pattern.len();
-		let remaining = &html_content[start_pos..];
-		
-		// Cas 1: eps123.length = 15
-		if remaining.starts_with(".length") {
-			if let Some(eq_pos) = remaining[7..].find('=') {
-				let after_eq = &remaining[7 + eq_pos + 1..];
-				let mut number_str = String::new();
-				
-				for ch in after_eq.trim().chars() {
-					if ch.is_ascii_digit() {
-						number_str.push(ch);
-					} else {
-						break;
-					}
-				}
-				
-				if let Ok(length) = number_str.parse::<i32>() {
-					if length > 0 {
-						return length;
-					}
-				}
-			}
-		}
-		
-		// Cas 2: eps123 = [...]
-		if let Some(eq_pos) = remaining.find('=') {
-			let after_eq = &remaining[eq_pos + 1..].trim_start();
-			if after_eq.starts_with('[') {
-				// Compter les éléments du tableau
-				if let Some(bracket_end) = after_eq.find(']') {
-					let array_content = &after_eq[1..bracket_end];
-					
-					// Compter les virgules + 1 (approximation simple)
-					let comma_count = array_content.matches(',').count();
-					if comma_count > 0 {
-						return (comma_count + 1) as i32;
-					}
+/// Tableau `epsN` lu comme du JSON. Le site écrit du JavaScript : chaînes entre
+/// apostrophes et virgule finale sont ramenées au JSON avant l'analyse.
+fn parse_eps_array(array_content: &str) -> Option<Vec<String>> {
+	let json = format!("[{}]", js_strings_to_json(array_content.trim().trim_end_matches(',')));
+	serde_json::from_str::<Vec<String>>(&json).ok().map(|urls| {
+		urls.into_iter()
+			.map(|url| url.trim().to_string())
+			.filter(|url| !url.is_empty())
+			.collect()
+	})
+}
+
+/// Réécrit les chaînes JavaScript en chaînes JSON. Seuls les délimiteurs
+/// changent : `'L\'Attaque'` devient `"L'Attaque"` et un `"` dans une chaîne
+/// entre apostrophes est échappé.
+fn js_strings_to_json(script: &str) -> String {
+	let mut json = String::with_capacity(script.len());
+	let mut chars = script.chars();
+	let mut quote: Option<char> = None;
+
+	while let Some(c) = chars.next() {
+		match (quote, c) {
+			(None, '\'' | '"') => {
+				quote = Some(c);
+				json.push('"');
+			}
+			(None, _) => json.push(c),
+			(Some(delimiter), _) if c == delimiter => {
+				quote = None;
+				json.push('"');
+			}
+			(Some(_), '\\') => match chars.next() {
+				// `\'` n'existe pas en JSON
+				Some('\'') => json.push('\''),
+				Some(escaped) => {
+					json.push('\\');
+					json.push(escaped);
 				}
-			}
+				None => json.push('\\'),
+			},
+			(Some(_), '"') => json.push_str("\\\""),
+			(Some(_), _) => json.push(c),
 		}
 	}
-	
-	0 // Aucun pattern trouvé
+
+	json
 }
 
-fn build_chapter_url(manga_key: &str) -> String {
-	let is_one_piece = helper::is_one_piece_manga(manga_key);
-	let scan_path = if is_one_piece { helper::SCAN_BW_PATH } else { helper::SCAN_VF_PATH };
-	
-	if manga_key.starts_with("http") {
-		// Remove trailing slash from manga_key if it exists to avoid double slash
-		let clean_key = manga_key.trim_end_matches('/');
-		format!("{}{}", clean_key, scan_path)
-	} else {
-		// Remove trailing slash from manga_key if it exists to avoid double slash
-		let clean_key = manga_key.trim_end_matches('/');
-		format!("{}{}{}", BASE_URL, clean_key, scan_path)
-	}
+/// Nombre de pages publié par `epsN.length = n` pour le chapitre à `chapter_index`
+fn find_eps_length(html_content: &str, chapter_index: i32) -> Option<i32> {
+	let pattern = format!("eps{}.length", chapter_index);
+	html_content.match_indices(&pattern).find_map(|(pos, _)| {
+		let value = html_content[pos + pattern.len()..].trim_start().strip_prefix('=')?.trim_start();
+		let end = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+		value[..end].parse::<i32>().ok().filter(|count| *count > 0)
+	})
 }
 
-// Get total chapters count from AnimeSama API
-fn get_total_chapters_from_api(manga_title: &str) -> Result<i32> {
-	use crate::helper::urlencode;
-	
-	let api_url = format!("https://anime-sama.org/s2/scans/get_nb_chap_et_img.php?oeuvre={}", 
-		urlencode(manga_title));
-	
-	match Request::get(&api_url)?
+/// Nombre de pages de chaque chapitre d'une série selon `get_nb_chap_et_img.php`,
+/// indexé par position : `{"1": 19, "2": 21, ...}`
+fn fetch_page_counts(manga_title: &str) -> Result<BTreeMap<i32, i32>> {
+	let api_url = format!("{}/get_nb_chap_et_img.php?oeuvre={}", CDN_URL, helper::urlencode(manga_title));
+	let json_string = Request::get(&api_url)?
 		.header("User-Agent", "curl/7.68.0")
-		.string() {
-		Ok(response_text) => {
-			let mut max_chapter = 0;
-			
-			// Parse JSON-like response to find chapter numbers
-			let mut start_pos = 0;
-			while let Some(quote_pos) = response_text[start_pos..].find("\"") {
-				start_pos += quote_pos + 1;
-				if let Some(end_quote) = response_text[start_pos..].find("\"") {
-					let key = &response_text[start_pos..start_pos + end_quote];
-					if let Ok(chapter_num) = key.parse::<i32>() {
-						if chapter_num > max_chapter {
-							max_chapter = chapter_num;
-						}
-					}
-					start_pos += end_quote + 1;
-				} else {
-					break;
-				}
-			}
-			
-			if max_chapter > 0 {
-				Ok(max_chapter)
-			} else {
-				// No chapters found, use a simple fallback
-				Ok(100) // Return default count
-			}
-		},
-		Err(e) => Err(e)
-	}
+		.string()?;
+
+	let counts: BTreeMap<String, serde_json::Value> = serde_json::from_str(&json_string)
+		.map_err(|_| AidokuError::message("Réponse de l'API invalide"))?;
+	Ok(counts
+		.into_iter()
+		.filter_map(|(index, count)| {
+			let count = match count {
+				serde_json::Value::Number(count) => count.as_i64()?,
+				serde_json::Value::String(count) => count.trim().parse().ok()?,
+				_ => return None,
+			};
+			Some((index.trim().parse::<i32>().ok()?, count as i32))
+		})
+		.filter(|(_, count)| *count > 0)
+		.collect())
 }
 
-// Convertir manga_key en titre formaté avec gestion des cas spéciaux
-// Nettoyer un titre extrait du HTML
-fn clean_extracted_title(title: &str) -> String {
-	title
-		.trim()
-		.replace("\n", " ")
-		.replace("\t", " ")
-		.replace("  ", " ") // Réduire les espaces multiples
-		.replace("&#039;", "'") // Nettoyer les entités HTML
-		.replace("&quot;", "\"")
-		.replace("&amp;", "&")
-		.replace("&lt;", "<")
-		.replace("&gt;", ">")
-		.replace("(Scan)", "") // Enlever les mentions parasites
-		.replace("- Scan", "")
-		.replace("- Scans", "")
-		.replace("Scan -", "")
-		.replace("Scans -", "")
-		.trim()
-		.to_string()
+/// Nombre de chapitres d'une série : l'API indexe les chapitres par position
+fn get_total_chapters_from_api(manga_title: &str) -> Result<i32> {
+	fetch_page_counts(manga_title)?
+		.keys()
+		.next_back()
+		.copied()
+		.ok_or_else(|| AidokuError::message("Série introuvable dans l'API"))
 }
 
-// Générer l'URL d'image selon le manga spécifique (logique déterministe)
-fn generate_image_url(manga_title: &str, chapter_index: i32, page: i32) -> String {
-	let encoded_title = helper::urlencode_path(manga_title);
-	
-	// Détection basée sur le titre du manga pour éviter les requêtes réseau
-	match manga_title {
-		"One Piece" => {
-			// For One Piece, use different CDN and format based on chapter range
-			if chapter_index >= 1046 {
-				// Chapters starting from One Shot position (1046+): new CDN with CDN index offset
-				// One Shot takes CDN position 1046, so chapters 1046+ are shifted +1 in CDN
-				// Examples: Chapter 1046 → .../1047/..., Chapter 1158 → .../1159/...
-				let cdn_index = chapter_index + 1; // +1 offset starting from chapter 1046
-				format!("{}/{}/{}/{}.jpg", CDN_URL, encoded_title, cdn_index, page)
-			} else if chapter_index <= 952 {
-				// Old chapters (1-952): legacy CDN with {chapter}_{page} format
-				format!("{}/{}/{}/{}_{}.jpg", CDN_URL_LEGACY, encoded_title, chapter_index, chapter_index, page)
-			} else {
-				// Middle chapters (953-1045): legacy CDN with prefix format
-				let prefix = chapter_index - 952;
-				format!("{}/{}/{}/{}_{}.jpg", CDN_URL_LEGACY, encoded_title, chapter_index, prefix, page)
-			}
-		}
-		"Dragon Ball" => {
-			// Dragon Ball utilise un format webp spécial avec tome fixe
-			let cdn_url = select_cdn_url(manga_title);
-			format!("{}/{}/1/DragonBallFixTome1-{:03}.webp", cdn_url, encoded_title, page)
-		}
-		_ => {
-			// Format par défaut: simple {page}.jpg (fonctionne pour 20th Century Boys, etc.)
-			let cdn_url = select_cdn_url(manga_title);
-			format!("{}/{}/{}/{}.jpg", cdn_url, encoded_title, chapter_index, page)
-		}
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aidoku::imports::html::Html;
+	use aidoku_test::aidoku_test;
+
+	#[aidoku_test]
+	fn every_genre_link_is_read() {
+		let html = Html::parse(
+			r#"<div id="sousBlocMiddle">
+				<h2 class="text-white text-xl font-bold uppercase">Genres</h2>
+				<a class="text-sm text-gray-300">Action</a>
+				<a class="text-sm text-gray-300">Aventure, Comédie</a>
+				<span>-</span>
+				<a class="text-sm text-gray-300">Shônen - Action</a>
+				<h2 class="text-white text-xl font-bold uppercase">Synopsis</h2>
+				<p>Un garçon au chapeau de paille...</p>
+				<a href="/catalogue/one-piece/scan/vf/">Scans</a>
+			</div>"#,
+		)
+		.unwrap();
+		assert_eq!(parse_genres(&html), ["Action", "Aventure", "Comédie", "Shônen"]);
 	}
-}
 
-fn manga_key_to_title(manga_key: &str) -> String {
-	let manga_slug = manga_key.split('/').last().unwrap_or("Manga");
-	
-	// Cas spéciaux avec caractères spéciaux et titres exacts du CDN
-	match manga_slug {
-		"kaiju-n8" => String::from("Kaiju N°8"),
-		"one-piece" | "one_piece" => String::from("One Piece"),
-		"20th-century-boys" => String::from("20th Century Boys"),
-		"21st-century-boys" => String::from("21st Century Boys"),
-		"dragon-ball" => String::from("Dragon Ball"),
-		"naruto" => String::from("Naruto"),
-		"bleach" => String::from("Bleach"),
-		"hunter-x-hunter" | "hunter_x_hunter" => String::from("Hunter x Hunter"),
-		"a-couple-of-cuckoos" => String::from("A Couple of Cuckoos"),
-		"a-sign-of-affection" => String::from("A Sign of Affection"),
-		"blue-lock" => String::from("Blue Lock"),
-		"chainsaw-man" => String::from("Chainsaw Man"),
-		"demon-slayer" => String::from("Demon Slayer"),
-		"my-hero-academia" => String::from("My Hero Academia"),
-		"attack-on-titan" => String::from("Attack on Titan"),
-		"tokyo-ghoul" => String::from("Tokyo Ghoul"),
-		"death-note" => String::from("Death Note"),
-		"spy-family" => String::from("Spy x Family"),
-		"jujutsu-kaisen" => String::from("Jujutsu Kaisen"),
-		"boruto" => String::from("Boruto"),
-		"black-clover" => String::from("Black Clover"),
-		"fire-force" => String::from("Fire Force"),
-		"dr-stone" => String::from("Dr. Stone"),
-		"versatile-mage" => String::from("Versatile Mage"),
-		_ => {
-			// Conversion générique améliorée: slug -> Title Case
-			manga_slug
-				.replace("-", " ")
-				.replace("_", " ")
-				.split_whitespace()
-				.map(|word| {
-					// Cas spéciaux pour certains mots
-					match word.to_lowercase().as_str() {
-						"n" => String::from("N°"), // Pour les numéros
-						"dr" => String::from("Dr."),
-						"mr" => String::from("Mr."),
-						"ms" => String::from("Ms."),
-						"x" => String::from("x"), // Pour "Spy x Family"
-						"vs" => String::from("vs"),
-						"of" | "the" | "and" | "in" | "on" | "at" | "to" | "for" => {
-							// Articles et prépositions en minuscules (sauf début)
-							word.to_lowercase()
-						},
-						_ => {
-							// Title Case normal
-							let mut chars = word.chars();
-							match chars.next() {
-								None => String::new(),
-								Some(first) => first.to_uppercase().chain(chars).collect(),
-							}
-						}
-					}
-				})
-				.collect::<Vec<String>>()
-				.join(" ")
-				.replace(" N ", " N°") // Remplacer "N " par "N°"
-		}
+	#[aidoku_test]
+	fn genres_in_a_single_link() {
+		let html = Html::parse(
+			r#"<div id="sousBlocMiddle"><h2>Genres</h2><a>Action, Drame, Fantasy</a><h2>Synopsis</h2></div>"#,
+		)
+		.unwrap();
+		assert_eq!(parse_genres(&html), ["Action", "Drame", "Fantasy"]);
+
+		let html = Html::parse(r#"<div id="sousBlocMiddle"><h2>Synopsis</h2><a>Scans</a></div>"#).unwrap();
+		assert!(parse_genres(&html).is_empty());
 	}
-}
 
+	#[test]
+	fn eps_arrays_accept_both_quotes() {
+		let urls = parse_eps_array("\n\t'https://s22.anime-sama.fr/s1/scans/One Piece/1/1.jpg',\n\t\"https://s22.anime-sama.fr/s1/scans/One Piece/1/2.jpg\",\n");
+		assert_eq!(
+			urls,
+			Some(vec![
+				"https://s22.anime-sama.fr/s1/scans/One Piece/1/1.jpg".to_string(),
+				"https://s22.anime-sama.fr/s1/scans/One Piece/1/2.jpg".to_string(),
+			])
+		);
+	}
 
+	#[test]
+	fn apostrophes_inside_urls_are_kept() {
+		assert_eq!(
+			parse_eps_array("\"https://s22.anime-sama.fr/s1/scans/L'Attaque des Titans/1/1.jpg\""),
+			Some(vec!["https://s22.anime-sama.fr/s1/scans/L'Attaque des Titans/1/1.jpg".to_string()])
+		);
+		assert_eq!(
+			parse_eps_array("'https://s22.anime-sama.fr/s1/scans/JoJo\\'s Bizarre Adventure/1/1.jpg'"),
+			Some(vec!["https://s22.anime-sama.fr/s1/scans/JoJo's Bizarre Adventure/1/1.jpg".to_string()])
+		);
+	}
+
+	#[test]
+	fn escaped_quotes_stay_escaped() {
+		assert_eq!(
+			parse_eps_array("'https://example.com/\\\"a\\\"/1.jpg', \"https://example.com/\\\"b\\\"/2.jpg\", 'https://example.com/\"c\"/3.jpg'"),
+			Some(vec![
+				"https://example.com/\"a\"/1.jpg".to_string(),
+				"https://example.com/\"b\"/2.jpg".to_string(),
+				"https://example.com/\"c\"/3.jpg".to_string(),
+			])
+		);
+	}
+
+	#[test]
+	fn empty_entries_are_dropped() {
+		assert_eq!(parse_eps_array("'', ' '"), Some(Vec::new()));
+		assert_eq!(parse_eps_array("'https://example.com/1.jpg"), None);
+	}
+}
@@ -339,13 +339,26 @@ fn parse_single_chapter_json(manga_key: &str, chapter: &Value) -> Result<Chapter
     };
     
     
+    // PizzaReader credits up to two teams per chapter, unused slots are null
+    let mut teams: Vec<String> = Vec::new();
+    if let Some(teams_array) = chapter.get("teams").and_then(|v| v.as_array()) {
+        for team in teams_array {
+            if let Some(name) = team.get("name").and_then(|n| n.as_str()) {
+                let name = name.trim().to_string();
+                if !name.is_empty() && !teams.contains(&name) {
+                    teams.push(name);
+                }
+            }
+        }
+    }
+
     Ok(Chapter {
         key: key.clone(),
         title: Some(title),
         chapter_number: Some(chapter_num),
         volume_number: None,
         date_uploaded: None,
-        scanlators: None,
+        scanlators: if teams.is_empty() { None } else { Some(teams) },
         language: None,
         locked: false,
         thumbnail: None,
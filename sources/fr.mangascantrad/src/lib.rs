@@ -11,6 +11,18 @@ use aidoku::{
 extern crate alloc;
 use alloc::{string::ToString};
 
+use madara_template::load_more::{
+    self, NAVIGATION_AJAX_SELECTOR, ORDER_BY_TITLE, ORDER_BY_TRENDING, ORDER_BY_VIEWS, load_more_body,
+};
+use madara_template::related::{RELATED_MANGA_SELECTOR, RELATED_TITLE_SELECTOR, RelatedSeries, related_series};
+use madara_template::scanlators::{
+    CHAPTER_TEAM_SELECTOR, SUMMARY_CONTENT_SELECTOR, SUMMARY_HEADING_SELECTOR, SUMMARY_ITEM_SELECTOR,
+    chapter_scanlators, fill_default_scanlators, series_scanlators,
+};
+use madara_template::protector::{CHAPTER_PROTECTOR_SELECTOR, protected_image_urls};
+use wordpress_template::cover::resolve_cover;
+use wordpress_template::stats::with_related;
+
 fn calculate_content_rating(tags: &Option<Vec<String>>) -> ContentRating {
     if let Some(tags) = tags {
         for tag in tags {
@@ -55,8 +67,6 @@ const MAX_RETRIES: u32 = 3;
 pub struct MangaScantrad;
 
 impl MangaScantrad {
-    const MIN_ENTRIES_FOR_PAGINATION: usize = 8;
-
     fn request_with_retry(url: &str, headers: Vec<(&str, &str)>) -> Result<Document> {
         let mut attempt = 0;
         loop {
@@ -244,14 +254,25 @@ impl MangaScantrad {
             }
         }).collect()
     }
+    /// "Séries similaires" cards of a series page
+    fn parse_related_series(&self, html: &Document) -> Vec<RelatedSeries> {
+        let cards: Vec<(String, String, Option<String>)> = html.select(RELATED_MANGA_SELECTOR)
+            .map(|items| items.into_iter().filter_map(|item| {
+                let link = item.select(RELATED_TITLE_SELECTOR).and_then(|links| links.first())?;
+                let cover = item.select("img")
+                    .and_then(|imgs| imgs.first())
+                    .and_then(|img| resolve_cover(|name| img.attr(name)));
+                Some((link.attr("href")?, link.text()?, cover))
+            }).collect())
+            .unwrap_or_default();
+
+        related_series(cards, |href| self.extract_manga_id(href))
+    }
+
     fn ajax_manga_list(&self, page: i32) -> Result<MangaPageResult> {
         let url = format!("{}/wp-admin/admin-ajax.php", BASE_URL);
 
-        let body = format!(
-            "action=madara_load_more&page={}&template=madara-core/content/content-archive&vars%5Borderby%5D=post_title&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Border%5D=ASC&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D=20&vars%5Bnumberposts%5D=20",
-            page - 1,
-            page
-        );
+        let body = load_more_body(page, ORDER_BY_TITLE);
 
         let headers = vec![
             ("User-Agent", USER_AGENT),
@@ -270,29 +291,12 @@ impl MangaScantrad {
     fn ajax_manga_listing(&self, listing_type: &str, page: i32) -> Result<MangaPageResult> {
         let url = format!("{}/wp-admin/admin-ajax.php", BASE_URL);
 
-        let body = match listing_type {
-            "popular" => {
-                format!(
-                    "action=madara_load_more&page={}&template=madara-core/content/content-archive&vars%5Borderby%5D=meta_value_num&vars%5Bmeta_key%5D=_wp_manga_views&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Border%5D=DESC&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D=20&vars%5Bnumberposts%5D=20",
-                    page - 1,
-                    page
-                )
-            },
-            "trending" => {
-                format!(
-                    "action=madara_load_more&page={}&template=madara-core/content/content-archive&vars%5Borderby%5D=trending&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Border%5D=DESC&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D=20&vars%5Bnumberposts%5D=20",
-                    page - 1,
-                    page
-                )
-            },
-            _ => {
-                format!(
-                    "action=madara_load_more&page={}&template=madara-core/content/content-archive&vars%5Borderby%5D=post_title&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Border%5D=ASC&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D=20&vars%5Bnumberposts%5D=20",
-                    page - 1,
-                    page
-                )
-            }
+        let order = match listing_type {
+            "popular" => ORDER_BY_VIEWS,
+            "trending" => ORDER_BY_TRENDING,
+            _ => ORDER_BY_TITLE,
         };
+        let body = load_more_body(page, order);
 
         let headers = vec![
             ("User-Agent", USER_AGENT),
@@ -319,11 +323,7 @@ impl MangaScantrad {
     ) -> Result<MangaPageResult> {
         let url = format!("{}/wp-admin/admin-ajax.php", BASE_URL);
 
-        let mut body = format!(
-            "action=madara_load_more&page={}&template=madara-core/content/content-archive&vars%5Borderby%5D=post_title&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Border%5D=ASC&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D=20&vars%5Bnumberposts%5D=20",
-            page - 1,
-            page
-        );
+        let mut body = load_more_body(page, ORDER_BY_TITLE);
 
         if let Some(search_query) = &query {
             if !search_query.is_empty() {
@@ -388,8 +388,10 @@ impl MangaScantrad {
 
         let ajax_doc = Self::post_request_with_retry(&ajax_url, ajax_headers, body_content.as_bytes())?;
 
-        if let Ok(chapters) = self.parse_ajax_chapters_response(ajax_doc) {
+        if let Ok(mut chapters) = self.parse_ajax_chapters_response(ajax_doc) {
             if !chapters.is_empty() {
+                // The AJAX list only credits teams per chapter, fall back to the series team
+                fill_default_scanlators(chapters.iter_mut().map(|chapter| &mut chapter.scanlators), self.extract_series_scanlators(&manga_page_doc));
                 return Ok(chapters);
             }
         }
@@ -397,6 +399,27 @@ impl MangaScantrad {
         self.parse_chapter_list(&manga_page_doc)
     }
     
+    fn extract_chapter_scanlators(&self, item: &aidoku::imports::html::Element) -> Option<Vec<String>> {
+        // Teams credited next to the release date in the chapter row
+        let names: Vec<String> = item.select(CHAPTER_TEAM_SELECTOR)
+            .map(|teams| teams.into_iter().filter_map(|team| team.text()).collect())
+            .unwrap_or_default();
+        chapter_scanlators(names)
+    }
+    
+    fn extract_series_scanlators(&self, html: &Document) -> Option<Vec<String>> {
+        let text = |item: &aidoku::imports::html::Element, selector: &str| item.select(selector)
+            .and_then(|elems| elems.first())
+            .and_then(|elem| elem.text())
+            .unwrap_or_default();
+        let items: Vec<(String, String)> = html.select(SUMMARY_ITEM_SELECTOR)
+            .map(|items| items.into_iter()
+                .map(|item| (text(&item, SUMMARY_HEADING_SELECTOR), text(&item, SUMMARY_CONTENT_SELECTOR)))
+                .collect())
+            .unwrap_or_default();
+        series_scanlators(items)
+    }
+    
     fn extract_manga_int_id(&self, html: &Document) -> Result<String> {
         
         // Look for the wp-manga-js-extra script tag (like in old Madara implementation)
@@ -563,7 +586,7 @@ impl MangaScantrad {
                         chapter_number: Some(chapter_number),
                         volume_number: None,
                         date_uploaded,
-                        scanlators: None,
+                        scanlators: self.extract_chapter_scanlators(item),
                         url: Some(url),
                         language: Some(String::from("fr")),
                         thumbnail: None,
@@ -592,12 +615,14 @@ impl MangaScantrad {
         ];
         
         let mut found_items = false;
+        let mut item_count = 0;
         for selector in &selectors {
             if let Some(items) = html.select(selector) {
                 let items_vec: Vec<_> = items.collect();
                 
                 if !items_vec.is_empty() {
                     found_items = true;
+                    item_count = items_vec.len();
                     
                     for (_idx, item) in items_vec.iter().enumerate() {
                         
@@ -638,22 +663,7 @@ impl MangaScantrad {
                         // Extract cover image using Madara template approach
                         let cover = item.select("img")
                             .and_then(|imgs| imgs.first())
-                            .and_then(|img| {
-                                // Same attribute priority as Madara template
-                                img.attr("data-src")
-                                    .or_else(|| img.attr("data-lazy-src"))
-                                    .or_else(|| img.attr("src"))
-                                    .or_else(|| img.attr("srcset"))
-                                    .or_else(|| img.attr("data-cfsrc"))
-                            })
-                            .map(|src| {
-                                // Clean up srcset if needed (take first URL)
-                                if src.contains(" ") {
-                                    src.split_whitespace().next().unwrap_or("").to_string()
-                                } else {
-                                    src.to_string()
-                                }
-                            })
+                            .and_then(|img| resolve_cover(|name| img.attr(name)))
                             .unwrap_or_default();
                         
 
@@ -708,9 +718,11 @@ impl MangaScantrad {
         } else {
         }
         
-        // Pagination logic: if we got any results, assume there might be more
-        // Madara typically returns 10-12 items per page, so we check if we got a reasonable amount
-        let has_next_page = entries.len() >= Self::MIN_ENTRIES_FOR_PAGINATION;
+        // An empty body or a short page means we reached the end of the listing
+        let has_next_page = load_more::has_next_page(
+            item_count,
+            html.select(NAVIGATION_AJAX_SELECTOR).is_some_and(|nav| !nav.is_empty()),
+        );
         
         Ok(MangaPageResult {
             entries,
@@ -750,21 +762,10 @@ impl MangaScantrad {
         let mut cover = String::new();
         for selector in &cover_selectors {
             if let Some(img_elem) = html.select(selector).and_then(|elems| elems.first()) {
-                // Use same attribute priority as Madara template
-                if let Some(src) = img_elem.attr("data-src")
-                    .or_else(|| img_elem.attr("data-lazy-src"))
-                    .or_else(|| img_elem.attr("src"))
-                    .or_else(|| img_elem.attr("srcset"))
-                    .or_else(|| img_elem.attr("data-cfsrc")) {
-                    if !src.is_empty() {
-                        // Clean up srcset if needed (take first URL)
-                        cover = if src.contains(" ") {
-                            src.split_whitespace().next().unwrap_or("").to_string()
-                        } else {
-                            src.to_string()
-                        };
-                        break;
-                    }
+                // Full resolution image, skipping lazy-load placeholders
+                if let Some(src) = resolve_cover(|name| img_elem.attr(name)) {
+                    cover = src;
+                    break;
                 }
             }
         }
@@ -950,7 +951,10 @@ impl MangaScantrad {
             cover: if cover.is_empty() { None } else { Some(cover) },
             authors,
             artists: None,
-            description: if description.is_empty() { None } else { Some(description) },
+            description: with_related(
+                if description.is_empty() { None } else { Some(description) },
+                self.parse_related_series(html).iter().map(|series| series.title.as_str()),
+            ),
             url: Some(format!("{}/manga/{}/", BASE_URL, manga_key)),
             tags: tags_opt,
             status,
@@ -1053,7 +1057,7 @@ impl MangaScantrad {
                             chapter_number: Some(chapter_number),
                             volume_number: None,
                             date_uploaded,
-                            scanlators: None,
+                            scanlators: self.extract_chapter_scanlators(item),
                             url: Some(url),
                             language: Some(String::from("fr")),
                             thumbnail: None,
@@ -1068,6 +1072,8 @@ impl MangaScantrad {
         if !found_chapters {
         }
 
+        fill_default_scanlators(chapters.iter_mut().map(|chapter| &mut chapter.scanlators), self.extract_series_scanlators(html));
+
         Ok(chapters)
     }
     
@@ -1205,6 +1211,18 @@ impl MangaScantrad {
     fn parse_page_list(&self, html: Document) -> Result<Vec<Page>> {
         let mut pages: Vec<Page> = Vec::new();
         
+        // Protected chapters have no <img> tags, the image list is encrypted in a script
+        if let Some(image_urls) = self.get_protected_image_urls(&html) {
+            for img_url in image_urls {
+                pages.push(Page {
+                    content: PageContent::Url(img_url, None),
+                    thumbnail: None,
+                    has_description: false,
+                    description: None,
+                });
+            }
+            return Ok(pages);
+        }
 
         // Primary selector (same as Madara template default)
         let image_selectors = [
@@ -1261,6 +1279,11 @@ impl MangaScantrad {
         Ok(pages)
     }
     
+    fn get_protected_image_urls(&self, html: &Document) -> Option<Vec<String>> {
+        let script = html.select(CHAPTER_PROTECTOR_SELECTOR)?.first()?;
+        protected_image_urls(script.data(), script.attr("src"))
+    }
+    
     // Helper function similar to Madara template's get_image_url
     fn get_image_url(&self, img_elem: &aidoku::imports::html::Element) -> String {
         // Try different attributes in same priority as Madara template
@@ -1297,4 +1320,63 @@ impl MangaScantrad {
     }
 }
 
-register_source!(MangaScantrad, ListingProvider, ImageRequestProvider);
\ No newline at end of file
+register_source!(MangaScantrad, ListingProvider, ImageRequestProvider);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aidoku::imports::html::Html;
+    use aidoku_test::aidoku_test;
+
+    // A `madara_load_more` answer holding `count` items, as the AJAX listing returns them
+    fn listing_page(count: usize, navigation: bool) -> Document {
+        let mut html = String::new();
+        for n in 1..=count {
+            html.push_str(&format!(
+                r#"<div class="page-item-detail manga">
+                    <div class="item-thumb c-image-hover">
+                        <a href="{base}/manga/serie-{n}/" title="Série {n}">
+                            <img src="{base}/wp-content/uploads/serie-{n}-175x238.jpg" class="img-responsive">
+                        </a>
+                    </div>
+                    <div class="item-summary">
+                        <div class="post-title font-title"><h3 class="h5"><a href="{base}/manga/serie-{n}/">Série {n}</a></h3></div>
+                    </div>
+                </div>"#,
+                base = BASE_URL,
+                n = n,
+            ));
+        }
+        if navigation {
+            html.push_str(r#"<div class="navigation-ajax"><div id="navigation-ajax" class="load-ajax">Charger plus</div></div>"#);
+        }
+        Html::parse(html).unwrap()
+    }
+
+    #[aidoku_test]
+    fn full_page_has_next_page() {
+        let result = MangaScantrad.parse_ajax_response(listing_page(load_more::POSTS_PER_PAGE, false)).unwrap();
+        assert_eq!(result.entries.len(), load_more::POSTS_PER_PAGE);
+        assert_eq!(result.entries[0].key, "serie-1");
+        assert_eq!(result.entries[0].title, "Série 1");
+        assert!(result.has_next_page);
+    }
+
+    #[aidoku_test]
+    fn short_page_is_the_last_one() {
+        let result = MangaScantrad.parse_ajax_response(listing_page(7, false)).unwrap();
+        assert_eq!(result.entries.len(), 7);
+        assert!(!result.has_next_page);
+
+        // Archive pages keep their "load more" button while posts remain
+        let result = MangaScantrad.parse_ajax_response(listing_page(7, true)).unwrap();
+        assert!(result.has_next_page);
+    }
+
+    #[aidoku_test]
+    fn empty_response_ends_the_listing() {
+        let result = MangaScantrad.parse_ajax_response(Html::parse("").unwrap()).unwrap();
+        assert!(result.entries.is_empty());
+        assert!(!result.has_next_page);
+    }
+}
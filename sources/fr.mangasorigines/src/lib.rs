@@ -11,6 +11,17 @@ use aidoku::{
 extern crate alloc;
 use alloc::{string::ToString};
 
+use madara_template::load_more::{
+    self, NAVIGATION_AJAX_SELECTOR, ORDER_BY_TITLE, ORDER_BY_TRENDING, ORDER_BY_VIEWS, load_more_body,
+};
+use madara_template::related::{RELATED_MANGA_SELECTOR, RELATED_TITLE_SELECTOR, RelatedSeries, related_series};
+use madara_template::scanlators::{CHAPTER_TEAM_SELECTOR, chapter_scanlators, fill_default_scanlators, series_scanlators};
+use madara_template::protector::{CHAPTER_PROTECTOR_SELECTOR, protected_image_urls};
+use wordpress_template::cover::resolve_cover;
+use wordpress_template::stats::{
+    chapter_counters, count_text, monthly_views_from_rank, stats_line, with_counters, with_header, with_related,
+};
+
 pub static BASE_URL: &str = "https://mangas-origines.fr";
 pub static USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) GSA/300.0.598994205 Mobile/15E148 Safari/604";
 
@@ -212,6 +223,21 @@ impl MangasOrigines {
         }
     }
 
+    /// "Séries similaires" cards of a series page
+    fn parse_related_series(&self, html: &Document) -> Vec<RelatedSeries> {
+        let cards: Vec<(String, String, Option<String>)> = html.select(RELATED_MANGA_SELECTOR)
+            .map(|items| items.into_iter().filter_map(|item| {
+                let link = item.select(RELATED_TITLE_SELECTOR).and_then(|links| links.first())?;
+                let cover = item.select("img")
+                    .and_then(|imgs| imgs.first())
+                    .and_then(|img| resolve_cover(|name| img.attr(name)));
+                Some((link.attr("href")?, link.text()?, cover))
+            }).collect())
+            .unwrap_or_default();
+
+        related_series(cards, |href| self.extract_manga_key(href))
+    }
+
     fn get_manga_listing_page(&self, page: i32) -> Result<MangaPageResult> {
         let url = format!("{}/oeuvre/?page={}", BASE_URL, page);
         
@@ -222,30 +248,19 @@ impl MangasOrigines {
             .header("Referer", BASE_URL)
             .html()?;
 
-        self.parse_manga_list(html, page)
+        self.parse_manga_list(html)
     }
 
     fn get_manga_listing(&self, list_type: &str, page: i32) -> Result<MangaPageResult> {
         let url = format!("{}/wp-admin/admin-ajax.php", BASE_URL);
         
         // Different payloads for different listing types (like mangascantrad)
-        let body = match list_type {
-            "popular" => {
-                format!(
-                    "action=madara_load_more&page={}&template=madara-core/content/content-archive&vars%5Borderby%5D=meta_value_num&vars%5Bmeta_key%5D=_wp_manga_views&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Border%5D=DESC&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D=20&vars%5Bnumberposts%5D=20",
-                    page - 1,
-                    page
-                )
-            },
-            "trending" => {
-                format!(
-                    "action=madara_load_more&page={}&template=madara-core/content/content-archive&vars%5Borderby%5D=trending&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Border%5D=DESC&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D=20&vars%5Bnumberposts%5D=20",
-                    page - 1,
-                    page
-                )
-            },
+        let order = match list_type {
+            "popular" => ORDER_BY_VIEWS,
+            "trending" => ORDER_BY_TRENDING,
             _ => return self.get_manga_listing_page(page)
         };
+        let body = load_more_body(page, order);
         
         let html = Request::post(&url)?
             .header("User-Agent", USER_AGENT)
@@ -257,7 +272,7 @@ impl MangasOrigines {
             .body(body.as_bytes())
             .html()?;
 
-        self.parse_manga_list(html, page)
+        self.parse_manga_list(html)
     }
 
     fn ajax_filtered_search(
@@ -269,11 +284,7 @@ impl MangasOrigines {
     ) -> Result<MangaPageResult> {
         let url = format!("{}/wp-admin/admin-ajax.php", BASE_URL);
         
-        let mut body = format!(
-            "action=madara_load_more&page={}&template=madara-core/content/content-archive&vars%5Borderby%5D=post_title&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Border%5D=ASC&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D=20&vars%5Bnumberposts%5D=20",
-            page - 1,
-            page
-        );
+        let mut body = load_more_body(page, ORDER_BY_TITLE);
         
         // Add search query if present
         if let Some(search_query) = &query {
@@ -311,14 +322,16 @@ impl MangasOrigines {
             .body(body.as_bytes())
             .html()?;
 
-        self.parse_manga_list(html, page)
+        self.parse_manga_list(html)
     }
 
-    fn parse_manga_list(&self, html: Document, page: i32) -> Result<MangaPageResult> {
+    fn parse_manga_list(&self, html: Document) -> Result<MangaPageResult> {
         let mut manga_list = Vec::new();
+        let mut item_count = 0;
         
         if let Some(items) = html.select("div.page-item-detail, div.row.c-tabs-item__content, .manga-item") {
             for item in items {
+                item_count += 1;
                 if let Some(link_elements) = item.select("h3 a, h5 a, h4 a, .post-title a, .manga-title a") {
                     if let Some(link) = link_elements.first() {
                         let title = link.text().unwrap_or_default().trim().to_string();
@@ -341,22 +354,9 @@ impl MangasOrigines {
                                 let mut cover_url: Option<String> = None;
                                 for selector in &cover_selectors {
                                     if let Some(img_elem) = item.select(selector).and_then(|elems| elems.first()) {
-                                        // Use same attribute priority as other Madara sources
-                                        if let Some(src) = img_elem.attr("data-src")
-                                            .or_else(|| img_elem.attr("data-lazy-src"))
-                                            .or_else(|| img_elem.attr("src"))
-                                            .or_else(|| img_elem.attr("srcset"))
-                                            .or_else(|| img_elem.attr("data-cfsrc")) {
-                                            if !src.is_empty() {
-                                                // Clean up srcset if needed (take first URL)
-                                                let clean_src = if src.contains(" ") {
-                                                    src.split_whitespace().next().unwrap_or("").to_string()
-                                                } else {
-                                                    src.to_string()
-                                                };
-                                                cover_url = Some(clean_src);
-                                                break;
-                                            }
+                                        if let Some(src) = resolve_cover(|name| img_elem.attr(name)) {
+                                            cover_url = Some(src);
+                                            break;
                                         }
                                     }
                                 }
@@ -384,9 +384,15 @@ impl MangasOrigines {
             }
         }
 
+        // An empty body or a short page means we reached the end of the listing
+        let has_next_page = load_more::has_next_page(
+            item_count,
+            html.select(NAVIGATION_AJAX_SELECTOR).is_some_and(|nav| !nav.is_empty()),
+        );
+
         Ok(MangaPageResult {
             entries: manga_list,
-            has_next_page: page < 50,
+            has_next_page,
         })
     }
 
@@ -399,12 +405,36 @@ impl MangasOrigines {
 
         let cover = self.get_cover_url(&html);
         let author = self.get_manga_author(&html);
-        let description = self.get_manga_description(&html);
         let status = self.get_manga_status(&html);
         let tags = self.get_manga_tags(&html);
 
+        let summary_items = self.get_summary_items(&html);
+        let artists = self.get_manga_artists(&html, &summary_items);
+        let series_type = Self::find_summary_value(&summary_items, &["type"]);
+        let description = self.build_description(
+            self.get_manga_description(&html),
+            &self.get_alternative_titles(&summary_items),
+            series_type.as_deref(),
+            Self::find_summary_value(&summary_items, &["année", "annee", "sortie", "release"]).as_deref(),
+            self.get_manga_rating(&html).as_deref(),
+            Self::find_summary_value(&summary_items, &["vues", "views"])
+                .and_then(|views| count_text(&views))
+                .as_deref(),
+            Self::find_summary_value(&summary_items, &["rang", "rank"])
+                .and_then(|rank| monthly_views_from_rank(&rank))
+                .as_deref(),
+        );
+        let description = with_related(
+            description,
+            self.parse_related_series(&html).iter().map(|series| series.title.as_str()),
+        );
+
         let content_rating = calculate_content_rating(&tags);
-        let viewer = calculate_viewer(&tags);
+        // The "Type" field (Manhwa, Manga...) is more reliable than genres for the reading direction
+        let viewer = match &series_type {
+            Some(series_type) => calculate_viewer(&Some(vec![series_type.clone()])),
+            None => calculate_viewer(&tags),
+        };
 
         let mut manga = Manga {
             key: key.clone(),
@@ -415,7 +445,7 @@ impl MangasOrigines {
             content_rating,
             viewer,
             authors: author,
-            artists: None,
+            artists,
             description,
             tags,
             chapters: None,
@@ -492,15 +522,26 @@ impl MangasOrigines {
                                     found_date
                                 };
 
+                                let counter = |selector: &str| {
+                                    chapter_element.select(selector)
+                                        .and_then(|elems| elems.first())
+                                        .and_then(|elem| elem.text())
+                                        .and_then(|text| count_text(&text))
+                                };
+                                let counters = chapter_counters(
+                                    counter(".chapter-views, span.view, .view-count"),
+                                    counter(".chapter-comments, .comment-count, .comments-count"),
+                                );
+
                                 chapters.push(Chapter {
                                     key: chapter_key,
-                                    title: Some(chapter_title),
+                                    title: with_counters(Some(chapter_title), counters),
                                     url: Some(chapter_url),
                                     language: Some("fr".to_string()),
                                     volume_number: None,
                                     chapter_number: Some(chapter_number),
                                     date_uploaded: date_published,
-                                    scanlators: None,
+                                    scanlators: self.extract_chapter_scanlators(&chapter_element),
                                     thumbnail: None,
                                     locked: false,
                                 });
@@ -515,9 +556,23 @@ impl MangasOrigines {
     }
 
     fn parse_chapter_list(&self, _manga_key: &str, html: &Document) -> Result<Vec<Chapter>> {
-        Ok(self.parse_chapter_elements_from_html(html))
+        let mut chapters = self.parse_chapter_elements_from_html(html);
+        fill_default_scanlators(chapters.iter_mut().map(|chapter| &mut chapter.scanlators), self.extract_series_scanlators(html));
+        Ok(chapters)
     }
-    
+
+    fn extract_chapter_scanlators(&self, chapter_element: &aidoku::imports::html::Element) -> Option<Vec<String>> {
+        // Teams credited next to the release date in the chapter row
+        let names: Vec<String> = chapter_element.select(CHAPTER_TEAM_SELECTOR)
+            .map(|teams| teams.into_iter().filter_map(|team| team.text()).collect())
+            .unwrap_or_default();
+        chapter_scanlators(names)
+    }
+
+    fn extract_series_scanlators(&self, html: &Document) -> Option<Vec<String>> {
+        series_scanlators(self.get_summary_items(html))
+    }
+
     fn ajax_chapter_list(&self, manga_key: &str) -> Result<Vec<Chapter>> {
         let manga_url = format!("{}/oeuvre/{}/", BASE_URL, manga_key);
 
@@ -546,7 +601,10 @@ impl MangasOrigines {
 
         let ajax_doc = Self::post_request_with_retry(&ajax_url, ajax_headers, body_content.as_bytes())?;
 
-        self.parse_ajax_chapters_response(ajax_doc)
+        // The AJAX list only credits teams per chapter, fall back to the series team
+        let mut chapters = self.parse_ajax_chapters_response(ajax_doc)?;
+        fill_default_scanlators(chapters.iter_mut().map(|chapter| &mut chapter.scanlators), self.extract_series_scanlators(&manga_page_doc));
+        Ok(chapters)
     }
     
     fn extract_manga_int_id(&self, html: &Document) -> Result<String> {
@@ -601,6 +659,19 @@ impl MangasOrigines {
     fn parse_page_list(&self, html: &Document) -> Result<Vec<Page>> {
         let mut pages = Vec::new();
         
+        // Protected chapters have no <img> tags, the image list is encrypted in a script
+        if let Some(image_urls) = self.get_protected_image_urls(html) {
+            for image_url in image_urls {
+                pages.push(Page {
+                    content: PageContent::Url(image_url, None),
+                    thumbnail: None,
+                    has_description: false,
+                    description: None,
+                });
+            }
+            return Ok(pages);
+        }
+        
         // Multiple selectors for different page layouts
         let image_selectors = [
             "div.page-break > img",              // Madara default selector
@@ -645,6 +716,11 @@ impl MangasOrigines {
         Ok(pages)
     }
     
+    fn get_protected_image_urls(&self, html: &Document) -> Option<Vec<String>> {
+        let script = html.select(CHAPTER_PROTECTOR_SELECTOR)?.first()?;
+        protected_image_urls(script.data(), script.attr("src"))
+    }
+    
     fn get_image_url(&self, img_elem: &aidoku::imports::html::Element) -> String {
         // Try different attributes in same priority as Madara template
         let mut img_url = img_elem.attr("data-src").unwrap_or_default();
@@ -745,21 +821,9 @@ impl MangasOrigines {
 
         for selector in &cover_selectors {
             if let Some(img_elem) = html.select(selector).and_then(|elems| elems.first()) {
-                // Use same attribute priority as other Madara sources
-                if let Some(src) = img_elem.attr("data-src")
-                    .or_else(|| img_elem.attr("data-lazy-src"))
-                    .or_else(|| img_elem.attr("src"))
-                    .or_else(|| img_elem.attr("srcset"))
-                    .or_else(|| img_elem.attr("data-cfsrc")) {
-                    if !src.is_empty() {
-                        // Clean up srcset if needed (take first URL)
-                        let clean_src = if src.contains(" ") {
-                            src.split_whitespace().next().unwrap_or("").to_string()
-                        } else {
-                            src.to_string()
-                        };
-                        return Some(clean_src);
-                    }
+                // Full resolution image, skipping lazy-load placeholders
+                if let Some(src) = resolve_cover(|name| img_elem.attr(name)) {
+                    return Some(src);
                 }
             }
         }
@@ -786,6 +850,130 @@ impl MangasOrigines {
         None
     }
 
+    /// Collects the heading/value pairs of the `div.post-content_item` summary block
+    fn get_summary_items(&self, html: &Document) -> Vec<(String, String)> {
+        let mut items = Vec::new();
+
+        if let Some(elements) = html.select("div.post-content_item") {
+            for element in elements {
+                let heading = element.select("div.summary-heading")
+                    .and_then(|elems| elems.first())
+                    .and_then(|elem| elem.text())
+                    .unwrap_or_default()
+                    .trim()
+                    .trim_end_matches(':')
+                    .trim()
+                    .to_lowercase();
+                let value = element.select("div.summary-content")
+                    .and_then(|elems| elems.first())
+                    .and_then(|elem| elem.text())
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string();
+
+                if !heading.is_empty() && !value.is_empty() {
+                    items.push((heading, value));
+                }
+            }
+        }
+
+        items
+    }
+
+    fn find_summary_value(items: &[(String, String)], headings: &[&str]) -> Option<String> {
+        items.iter()
+            .find(|(heading, _)| headings.iter().any(|h| heading.starts_with(h)))
+            .map(|(_, value)| value.clone())
+            .filter(|value| !value.is_empty() && value != "N/A" && value != "Updating")
+    }
+
+    fn get_alternative_titles(&self, items: &[(String, String)]) -> Vec<String> {
+        let Some(value) = Self::find_summary_value(items, &["alternati", "autre", "titre"]) else {
+            return Vec::new();
+        };
+
+        let mut titles: Vec<String> = Vec::new();
+        for title in value.split(|c: char| c == ',' || c == ';').flat_map(|part| part.split(" / ")) {
+            let title = title.trim().to_string();
+            if !title.is_empty() && !titles.contains(&title) {
+                titles.push(title);
+            }
+        }
+        titles
+    }
+
+    fn get_manga_artists(&self, html: &Document, items: &[(String, String)]) -> Option<Vec<String>> {
+        let mut artists: Vec<String> = Vec::new();
+
+        if let Some(artist_elements) = html.select("div.artist-content a") {
+            for artist in artist_elements {
+                let name = artist.text().unwrap_or_default().trim().to_string();
+                if !name.is_empty() && !artists.contains(&name) {
+                    artists.push(name);
+                }
+            }
+        }
+
+        if artists.is_empty() {
+            if let Some(value) = Self::find_summary_value(items, &["artiste", "artist", "dessin"]) {
+                for name in value.split(',') {
+                    let name = name.trim().to_string();
+                    if !name.is_empty() && !artists.contains(&name) {
+                        artists.push(name);
+                    }
+                }
+            }
+        }
+
+        if artists.is_empty() { None } else { Some(artists) }
+    }
+
+    fn get_manga_rating(&self, html: &Document) -> Option<String> {
+        let average = html.select("span#averagerate, .post-total-rating .score")
+            .and_then(|elems| elems.first())
+            .and_then(|elem| elem.text())
+            .map(|text| text.trim().replace(',', "."))
+            .and_then(|text| text.parse::<f32>().ok())
+            .filter(|average| *average > 0.0)?;
+
+        let votes = html.select("span#countrate")
+            .and_then(|elems| elems.first())
+            .and_then(|elem| elem.text())
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty() && text != "0");
+
+        match votes {
+            Some(votes) => Some(format!("{:.1}/5 ({} votes)", average, votes)),
+            None => Some(format!("{:.1}/5", average)),
+        }
+    }
+
+    /// Prepends the alternative titles and series info to the synopsis
+    fn build_description(
+        &self,
+        synopsis: Option<String>,
+        alternative_titles: &[String],
+        series_type: Option<&str>,
+        release_year: Option<&str>,
+        rating: Option<&str>,
+        views: Option<&str>,
+        monthly_views: Option<&str>,
+    ) -> Option<String> {
+        let mut header = Vec::new();
+        if !alternative_titles.is_empty() {
+            header.push(format!("Titres alternatifs : {}", alternative_titles.join(", ")));
+        }
+        header.extend(stats_line(&[
+            ("Type", series_type),
+            ("Année", release_year),
+            ("Note", rating),
+            ("Vues", views),
+            ("Vues mensuelles", monthly_views),
+        ]));
+
+        with_header(synopsis, &header)
+    }
+
     fn get_manga_description(&self, html: &Document) -> Option<String> {
         let description_selectors = [
             "div.summary__content p",
@@ -975,4 +1163,63 @@ impl MangasOrigines {
     }
 }
 
-register_source!(MangasOrigines, ListingProvider, ImageRequestProvider);
\ No newline at end of file
+register_source!(MangasOrigines, ListingProvider, ImageRequestProvider);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aidoku::imports::html::Html;
+    use aidoku_test::aidoku_test;
+
+    // A `madara_load_more` answer holding `count` items, as the AJAX listing returns them
+    fn listing_page(count: usize, navigation: bool) -> Document {
+        let mut html = String::new();
+        for n in 1..=count {
+            html.push_str(&format!(
+                r#"<div class="page-item-detail manga">
+                    <div class="item-thumb c-image-hover">
+                        <a href="{base}/oeuvre/serie-{n}/" title="Série {n}">
+                            <img src="{base}/wp-content/uploads/serie-{n}-175x238.jpg" class="img-responsive">
+                        </a>
+                    </div>
+                    <div class="item-summary">
+                        <div class="post-title font-title"><h3 class="h5"><a href="{base}/oeuvre/serie-{n}/">Série {n}</a></h3></div>
+                    </div>
+                </div>"#,
+                base = BASE_URL,
+                n = n,
+            ));
+        }
+        if navigation {
+            html.push_str(r#"<div class="navigation-ajax"><div id="navigation-ajax" class="load-ajax">Charger plus</div></div>"#);
+        }
+        Html::parse(html).unwrap()
+    }
+
+    #[aidoku_test]
+    fn full_page_has_next_page() {
+        let result = MangasOrigines.parse_manga_list(listing_page(load_more::POSTS_PER_PAGE, false)).unwrap();
+        assert_eq!(result.entries.len(), load_more::POSTS_PER_PAGE);
+        assert_eq!(result.entries[0].key, "serie-1");
+        assert_eq!(result.entries[0].title, "Série 1");
+        assert!(result.has_next_page);
+    }
+
+    #[aidoku_test]
+    fn short_page_is_the_last_one() {
+        let result = MangasOrigines.parse_manga_list(listing_page(7, false)).unwrap();
+        assert_eq!(result.entries.len(), 7);
+        assert!(!result.has_next_page);
+
+        // Archive pages keep their "load more" button while posts remain
+        let result = MangasOrigines.parse_manga_list(listing_page(7, true)).unwrap();
+        assert!(result.has_next_page);
+    }
+
+    #[aidoku_test]
+    fn empty_response_ends_the_listing() {
+        let result = MangasOrigines.parse_manga_list(Html::parse("").unwrap()).unwrap();
+        assert!(result.entries.is_empty());
+        assert!(!result.has_next_page);
+    }
+}
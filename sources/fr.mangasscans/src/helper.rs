@@ -1,311 +0,0 @@
-use aidoku::alloc::{String, Vec, format};
-use aidoku::FilterValue;
-use aidoku::imports::html::Document;
-
-pub fn urlencode(string: String) -> String {
-    let mut result: Vec<u8> = Vec::with_capacity(string.len() * 3);
-    let hex = "0123456789abcdef".as_bytes();
-    let bytes = string.as_bytes();
-
-    for byte in bytes {
-        let curr = *byte;
-        if (b'a'..=b'z').contains(&curr)
-            || (b'A'..=b'Z').contains(&curr)
-            || (b'0'..=b'9').contains(&curr)
-            || curr == b'-'
-            || curr == b'_'
-            || curr == b'.'
-            || curr == b'~'
-        {
-            result.push(curr);
-        } else if curr == b' ' {
-            result.push(b'+');
-        } else {
-            result.push(b'%');
-            result.push(hex[curr as usize >> 4]);
-            result.push(hex[curr as usize & 15]);
-        }
-    }
-
-    String::from_utf8(result).unwrap_or_default()
-}
-
-pub fn make_absolute_url(base_url: &str, url: &str) -> String {
-    if url.starts_with("http") {
-        String::from(url)
-    } else if url.starts_with("//") {
-        format!("https:{}", url)
-    } else if url.starts_with('/') {
-        format!("{}{}", base_url, url)
-    } else {
-        format!("{}/{}", base_url, url)
-    }
-}
-
-pub fn extract_chapter_number(title: &str) -> f32 {
-    let title_lower = title.to_lowercase();
-
-    if let Some(idx) = title_lower.find("chapitre") {
-        let after = &title_lower[idx + 8..].trim_start();
-        if let Some(num_str) = after.split_whitespace().next() {
-            let cleaned = num_str.replace(',', ".");
-            if let Ok(num) = cleaned.parse::<f32>() {
-                return num;
-            }
-        }
-    }
-
-    if let Some(idx) = title_lower.find("chapter") {
-        let after = &title_lower[idx + 7..].trim_start();
-        if let Some(num_str) = after.split_whitespace().next() {
-            let cleaned = num_str.replace(',', ".");
-            if let Ok(num) = cleaned.parse::<f32>() {
-                return num;
-            }
-        }
-    }
-
-    if let Some(idx) = title_lower.find("ch.") {
-        let after = &title_lower[idx + 3..].trim_start();
-        if let Some(num_str) = after.split_whitespace().next() {
-            let cleaned = num_str.replace(',', ".");
-            if let Ok(num) = cleaned.parse::<f32>() {
-                return num;
-            }
-        }
-    }
-
-    -1.0
-}
-
-pub fn parse_status(status_text: &str) -> aidoku::MangaStatus {
-    use aidoku::MangaStatus;
-
-    let status_lower = status_text.trim().to_lowercase();
-
-    if status_lower.contains("en cours") || status_lower.contains("ongoing") {
-        return MangaStatus::Ongoing;
-    }
-    if status_lower.contains("complété")
-        || status_lower.contains("completed")
-        || status_lower.contains("termine")
-        || status_lower.contains("terminé") {
-        return MangaStatus::Completed;
-    }
-    if status_lower.contains("en pause") || status_lower.contains("hiatus") {
-        return MangaStatus::Hiatus;
-    }
-    if status_lower.contains("abandonné")
-        || status_lower.contains("cancelled")
-        || status_lower.contains("dropped") {
-        return MangaStatus::Cancelled;
-    }
-    if status_lower.contains("partenaire") {
-        return MangaStatus::Ongoing;
-    }
-
-    MangaStatus::Unknown
-}
-
-pub fn parse_chapter_date(text: &str) -> Option<i64> {
-    let text_lower = text.to_lowercase();
-
-    let parts: Vec<&str> = text_lower.split_whitespace().collect();
-
-    if parts.len() < 3 {
-        return None;
-    }
-
-    let mut month_num = 0;
-    let mut month_index = 0;
-
-    for (i, part) in parts.iter().enumerate() {
-        month_num = match *part {
-            "janvier" | "january" => 1,
-            "février" | "february" | "fevrier" => 2,
-            "mars" | "march" => 3,
-            "avril" | "april" => 4,
-            "mai" | "may" => 5,
-            "juin" | "june" => 6,
-            "juillet" | "july" => 7,
-            "août" | "august" | "aout" => 8,
-            "septembre" | "september" => 9,
-            "octobre" | "october" => 10,
-            "novembre" | "november" => 11,
-            "décembre" | "december" | "decembre" => 12,
-            _ => continue,
-        };
-
-        if month_num > 0 {
-            month_index = i;
-            break;
-        }
-    }
-
-    if month_num == 0 {
-        return None;
-    }
-
-    let day_index = if month_index > 0 && parts[month_index - 1].chars().all(|c| c.is_ascii_digit()) {
-        month_index - 1
-    } else if month_index + 1 < parts.len() {
-        month_index + 1
-    } else {
-        return None;
-    };
-
-    let year_index = if day_index < month_index {
-        if month_index + 1 < parts.len() { month_index + 1 } else { return None; }
-    } else {
-        if day_index + 1 < parts.len() { day_index + 1 } else { return None; }
-    };
-
-    let day = parts[day_index].trim_end_matches(',').parse::<i64>().ok()?;
-    let year = parts[year_index].trim_end_matches(',').parse::<i64>().ok()?;
-
-    let years_since_epoch = year - 1970;
-    let leap_years = count_leap_years_since_1970(year);
-    let mut total_days: i64 = years_since_epoch * 365 + leap_years;
-
-    let days_in_month = [31, if is_leap_year(year) { 29 } else { 28 }, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-
-    for m in 1..month_num {
-        total_days += days_in_month[(m - 1) as usize];
-    }
-
-    total_days += day - 1;
-
-    Some(total_days * 86400)
-}
-
-fn is_leap_year(year: i64) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
-}
-
-fn count_leap_years_since_1970(year: i64) -> i64 {
-    let leap_years_by_4 = (year - 1) / 4 - (1970 - 1) / 4;
-    let leap_years_by_100 = (year - 1) / 100 - (1970 - 1) / 100;
-    let leap_years_by_400 = (year - 1) / 400 - (1970 - 1) / 400;
-
-    leap_years_by_4 - leap_years_by_100 + leap_years_by_400
-}
-
-pub fn build_filter_params(filters: Vec<FilterValue>) -> String {
-    let mut params = Vec::new();
-
-    for filter in filters {
-        match filter {
-            FilterValue::Select { id, value } => {
-                if !value.is_empty() {
-                    let filter_id = id.as_str();
-                    params.push(format!("{}={}", filter_id, value));
-                }
-            }
-            FilterValue::MultiSelect { id, included, excluded } => {
-                if id.as_str() == "genre" {
-                    for genre_id in included {
-                        if !genre_id.is_empty() {
-                            params.push(format!("genre%5B%5D={}", genre_id));
-                        }
-                    }
-                    for genre_id in excluded {
-                        if !genre_id.is_empty() {
-                            params.push(format!("genre%5B%5D=-{}", genre_id));
-                        }
-                    }
-                }
-            }
-            _ => {}
-        }
-    }
-
-    if params.is_empty() {
-        String::new()
-    } else {
-        format!("&{}", params.join("&"))
-    }
-}
-
-pub fn detect_pagination(html: &Document) -> i32 {
-    const MAX_PAGINATION_PAGES: i32 = 150;
-
-    let mut total_pages = 1;
-
-    let pagination_selectors = [".pagination", ".page-numbers", ".hpage", "nav.pagination"];
-    for selector in &pagination_selectors {
-        if let Some(pagination) = html.select(selector) {
-            if let Some(text) = pagination.text() {
-                if let Some(total) = extract_pagination_total(&text) {
-                    total_pages = total;
-                    break;
-                }
-            }
-        }
-    }
-
-    if total_pages > MAX_PAGINATION_PAGES {
-        total_pages = MAX_PAGINATION_PAGES;
-    }
-
-    total_pages
-}
-
-fn extract_pagination_total(text: &str) -> Option<i32> {
-    const MIN_PAGINATION_VALUE: i32 = 2;
-    const MAX_PAGINATION_VALUE: i32 = 150;
-    const OF_KEYWORD_LENGTH: usize = 4;
-    const SUR_KEYWORD_LENGTH: usize = 5;
-
-    if let Some(of_pos) = text.find(" of ") {
-        let after_of = &text[of_pos + OF_KEYWORD_LENGTH..];
-        if let Some(first_number) = after_of.split_whitespace().next() {
-            let clean_number: String = first_number
-                .chars()
-                .take_while(|c| c.is_ascii_digit())
-                .collect();
-
-            if let Ok(pages) = clean_number.parse::<i32>() {
-                if pages >= MIN_PAGINATION_VALUE && pages <= MAX_PAGINATION_VALUE {
-                    return Some(pages);
-                }
-            }
-        }
-    }
-
-    if let Some(sur_pos) = text.find(" sur ") {
-        let after_sur = sur_pos + SUR_KEYWORD_LENGTH;
-        if after_sur < text.len() {
-            let after = &text[after_sur..];
-            if let Some(first_number) = after.split_whitespace().next() {
-                let clean_number: String = first_number
-                    .chars()
-                    .take_while(|c| c.is_ascii_digit())
-                    .collect();
-
-                if let Ok(pages) = clean_number.parse::<i32>() {
-                    if pages >= MIN_PAGINATION_VALUE && pages <= MAX_PAGINATION_VALUE {
-                        return Some(pages);
-                    }
-                }
-            }
-        }
-    }
-
-    let numbers: Vec<i32> = text
-        .split_whitespace()
-        .filter_map(|word| {
-            let clean_word: String = word
-                .chars()
-                .filter(|c| c.is_ascii_digit())
-                .collect();
-            clean_word.parse::<i32>().ok()
-        })
-        .filter(|&n| n >= MIN_PAGINATION_VALUE && n <= MAX_PAGINATION_VALUE)
-        .collect();
-
-    if let Some(&max_num) = numbers.iter().max() {
-        return Some(max_num);
-    }
-
-    None
-}
@@ -4,7 +4,11 @@ use aidoku::{
     Chapter, FilterValue, ImageRequestProvider, Listing, ListingProvider, Manga, MangaPageResult,
     Page, PageContext, Result, Source,
     alloc::{String, Vec},
-    imports::{net::Request, std::send_partial_result},
+    imports::{
+        defaults::defaults_get,
+        net::Request,
+        std::send_partial_result,
+    },
     prelude::*,
 };
 
@@ -14,6 +18,9 @@ mod helper;
 pub static BASE_URL: &str = "https://poseidon-scans.com";
 pub static API_URL: &str = "https://poseidon-scans.com/api";
 
+/// Setting choosing whether early-access chapters are listed locked or hidden
+const EARLY_ACCESS_SETTING: &str = "earlyAccessChapters";
+
 pub struct PoseidonScans;
 
 impl Source for PoseidonScans {
@@ -101,7 +108,10 @@ impl Source for PoseidonScans {
         }
 
         if needs_chapters {
-            let chapters = parser::parse_chapter_list(manga.key, &html)?;
+            let mut chapters = parser::parse_chapter_list(manga.key, &html)?;
+            if defaults_get::<String>(EARLY_ACCESS_SETTING).as_deref() == Some("hidden") {
+                chapters.retain(|chapter| !chapter.locked);
+            }
             updated_manga.chapters = Some(chapters);
         }
 
@@ -141,6 +151,21 @@ impl ListingProvider for PoseidonScans {
                 let response = helper::build_api_request(&url)?.string()?;
                 parser::parse_popular_manga(response)
             },
+            _ => {
+                        return Ok(MangaPageResult {
+                            entries: Vec::new(),
+                            has_next_page: false,
+                        });
+                    }
+                };
+
+                let url = format!("{}/serie/{}", BASE_URL, helper::urlencode(key.clone()));
+                let html = helper::build_html_request(&url)?.html()?;
+                Ok(MangaPageResult {
+                    entries: parser::parse_related_manga(&key, &html),
+                    has_next_page: false,
+                })
+            },
             _ => {
                 Ok(MangaPageResult {
                     entries: Vec::new(),
@@ -1,7 +1,7 @@
 use crate::BASE_URL;
 use aidoku::{
 	alloc::{
-		collections::BTreeSet,
+		collections::{BTreeMap, BTreeSet},
 		format,
 		string::ToString,
 		vec, String, Vec,
@@ -498,6 +498,18 @@ pub fn parse_manga_details(manga_key: String, html: &Document) -> Result<Manga>
 	let content_rating = calculate_content_rating(&tags);
 	let viewer = calculate_viewer(&tags);
 
+	// Related series are listed after the synopsis
+	let related: Vec<String> = parse_related_manga(&manga_key, html)
+		.into_iter()
+		.map(|manga| manga.title)
+		.collect();
+	if !related.is_empty() {
+		if !description.is_empty() {
+			description.push_str("\n\n");
+		}
+		description.push_str(&format!("Séries similaires : {}", related.join(", ")));
+	}
+
 	Ok(Manga {
 		key: manga_key.clone(),
 		title,
@@ -573,6 +585,64 @@ fn parse_iso_date(date_str: &str) -> Option<i64> {
 	None
 }
 
+// Date a premium chapter becomes free to read
+fn public_release_date(chapter: &serde_json::Value) -> Option<i64> {
+	chapter
+		.get("premiumUntil")
+		.and_then(|v| v.as_str())
+		.and_then(parse_iso_date)
+}
+
+// Title of an early-access chapter, its own title (or "Chapitre 12") followed by
+// the label: "Chapitre 12 (accès anticipé, public le 24/10/2025)"
+fn early_access_title(title: Option<&str>, chapter_number: f32, release_date: Option<i64>) -> String {
+	let title = title
+		.map(str::trim)
+		.filter(|title| !title.is_empty())
+		.map(String::from)
+		.unwrap_or_else(|| format!("Chapitre {}", chapter_number));
+	match release_date.and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)) {
+		Some(date) => format!("{} (accès anticipé, public le {})", title, date.format("%d/%m/%Y")),
+		None => format!("{} (accès anticipé)", title),
+	}
+}
+
+// Early access covers every chapter from the first premium one, even those the
+// page doesn't flag. Those chapters are locked and titled with their public
+// release date when known, then the list is sorted newest first.
+fn finish_chapter_list(mut chapters: Vec<Chapter>, release_dates: &BTreeMap<String, i64>) -> Vec<Chapter> {
+	let min_premium_chapter = chapters
+		.iter()
+		.filter(|ch| ch.locked)
+		.filter_map(|ch| ch.chapter_number)
+		.min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+	if let Some(min_num) = min_premium_chapter {
+		for chapter in &mut chapters {
+			let Some(ch_num) = chapter.chapter_number else {
+				continue;
+			};
+			if ch_num >= min_num {
+				chapter.locked = true;
+				chapter.title = Some(early_access_title(
+					chapter.title.as_deref(),
+					ch_num,
+					release_dates.get(&chapter.key).copied(),
+				));
+			}
+		}
+	}
+
+	chapters.sort_by(|a, b| match (a.chapter_number, b.chapter_number) {
+		(Some(a_num), Some(b_num)) => b_num.partial_cmp(&a_num).unwrap_or(Ordering::Equal),
+		(Some(_), None) => Ordering::Less,
+		(None, Some(_)) => Ordering::Greater,
+		(None, None) => Ordering::Equal,
+	});
+
+	chapters
+}
+
 // Parse chapters from Next.js RSC streaming data (self.__next_f.push)
 // This is the primary method as it contains isPremium field
 fn parse_chapters_from_nextdata(html: &Document, manga_key: &str) -> Result<Vec<Chapter>> {
@@ -687,6 +757,7 @@ fn parse_chapters_from_nextdata(html: &Document, manga_key: &str) -> Result<Vec<
 														match serde_json::from_value::<Vec<serde_json::Value>>(chapters_value.clone()) {
 															Ok(chapters_array) => {
 														let mut chapters: Vec<Chapter> = Vec::new();
+														let mut release_dates: BTreeMap<String, i64> = BTreeMap::new();
 
 														for chapter in chapters_array.iter() {
 															let chapter_number = chapter
@@ -724,6 +795,12 @@ fn parse_chapters_from_nextdata(html: &Document, manga_key: &str) -> Result<Vec<
 																	chapter_key
 																);
 
+																if is_premium {
+																	if let Some(date) = public_release_date(chapter) {
+																		release_dates.insert(chapter_key.clone(), date);
+																	}
+																}
+
 																// Parse createdAt date
 																let date_uploaded = chapter
 																	.get("createdAt")
@@ -747,33 +824,7 @@ fn parse_chapters_from_nextdata(html: &Document, manga_key: &str) -> Result<Vec<
 
 														if !chapters.is_empty() {
 
-															let min_premium_chapter = chapters
-																.iter()
-																.filter(|ch| ch.locked)
-																.filter_map(|ch| ch.chapter_number)
-																.min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
-
-															if let Some(min_num) = min_premium_chapter {
-																for chapter in &mut chapters {
-																	if let Some(ch_num) = chapter.chapter_number {
-																		if ch_num >= min_num {
-																			chapter.locked = true;
-																		}
-																	}
-																}
-															} else {
-															}
-
-															chapters.sort_by(|a, b| {
-																match (a.chapter_number, b.chapter_number) {
-																	(Some(a_num), Some(b_num)) => b_num.partial_cmp(&a_num).unwrap_or(Ordering::Equal),
-																	(Some(_), None) => Ordering::Less,
-																	(None, Some(_)) => Ordering::Greater,
-																	(None, None) => Ordering::Equal,
-																}
-															});
-
-															return Ok(chapters);
+															return Ok(finish_chapter_list(chapters, &release_dates));
 														}
 													}
 													Err(_e) => {
@@ -832,6 +883,7 @@ fn parse_chapters_from_nextdata(html: &Document, manga_key: &str) -> Result<Vec<
 							for (_idx, chapters_data) in possible_paths.iter().enumerate() {
 								if let Some(chapters_array) = chapters_data.as_array() {
 									let mut chapters: Vec<Chapter> = Vec::new();
+									let mut release_dates: BTreeMap<String, i64> = BTreeMap::new();
 
 									for chapter in chapters_array.iter() {
 										let chapter_number = chapter.get("number").and_then(|v| {
@@ -871,6 +923,12 @@ fn parse_chapters_from_nextdata(html: &Document, manga_key: &str) -> Result<Vec<
 											BASE_URL, manga_key, chapter_key
 										);
 
+										if is_premium {
+											if let Some(date) = public_release_date(chapter) {
+												release_dates.insert(chapter_key.clone(), date);
+											}
+										}
+
 										// Parse createdAt date
 										let date_uploaded = chapter
 											.get("createdAt")
@@ -894,39 +952,7 @@ fn parse_chapters_from_nextdata(html: &Document, manga_key: &str) -> Result<Vec<
 									if !chapters.is_empty() {
 										// );
 
-										let min_premium_chapter = chapters
-											.iter()
-											.filter(|ch| ch.locked)
-											.filter_map(|ch| ch.chapter_number)
-											.min_by(|a, b| {
-												a.partial_cmp(b).unwrap_or(Ordering::Equal)
-											});
-
-										if let Some(min_num) = min_premium_chapter {
-											for chapter in &mut chapters {
-												if let Some(ch_num) = chapter.chapter_number {
-													if ch_num >= min_num {
-														chapter.locked = true;
-													}
-												}
-											}
-										} else {
-											// 	"[PoseidonScans] No premium chapters detected"
-											// );
-										}
-
-										chapters.sort_by(|a, b| {
-											match (a.chapter_number, b.chapter_number) {
-												(Some(a_num), Some(b_num)) => b_num
-													.partial_cmp(&a_num)
-													.unwrap_or(Ordering::Equal),
-												(Some(_), None) => Ordering::Less,
-												(None, Some(_)) => Ordering::Greater,
-												(None, None) => Ordering::Equal,
-											}
-										});
-
-										return Ok(chapters);
+										return Ok(finish_chapter_list(chapters, &release_dates));
 									}
 								} else {
 								}
@@ -1160,32 +1186,8 @@ pub fn parse_chapter_list(manga_key: String, html: &Document) -> Result<Vec<Chap
 		}
 	}
 
-	// Post-processing: mark all chapters >= min premium as locked
-	let min_premium_chapter = chapters
-		.iter()
-		.filter(|ch| ch.locked)
-		.filter_map(|ch| ch.chapter_number)
-		.min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
-
-	if let Some(min_num) = min_premium_chapter {
-		for chapter in &mut chapters {
-			if let Some(ch_num) = chapter.chapter_number {
-				if ch_num >= min_num {
-					chapter.locked = true;
-				}
-			}
-		}
-	}
-
-	// Sort chapters by number in descending order (newest first)
-	chapters.sort_by(|a, b| match (a.chapter_number, b.chapter_number) {
-		(Some(a_num), Some(b_num)) => b_num.partial_cmp(&a_num).unwrap_or(Ordering::Equal),
-		(Some(_), None) => Ordering::Less,
-		(None, Some(_)) => Ordering::Greater,
-		(None, None) => Ordering::Equal,
-	});
-
-	Ok(chapters)
+	// No release dates in JSON-LD, early-access chapters keep a plain title
+	Ok(finish_chapter_list(chapters, &BTreeMap::new()))
 }
 
 fn parse_chapter_list_from_html(html: &Document) -> Result<Vec<Chapter>> {
@@ -1562,6 +1564,99 @@ fn extract_chapter_number_from_id(chapter_id: &str) -> Option<f32> {
 	chapter_id.parse::<f32>().ok()
 }
 
+/// JSON payloads of a Next.js page: the `__NEXT_DATA__` script, or the
+/// `self.__next_f.push([id, "id:json"])` chunks of the RSC stream.
+fn next_payloads(html: &Document) -> Vec<serde_json::Value> {
+	let mut payloads = Vec::new();
+	let Some(scripts) = html.select("script") else {
+		return payloads;
+	};
+
+	for script in scripts {
+		let Some(content) = script.data() else {
+			continue;
+		};
+		if script.attr("id").as_deref() == Some("__NEXT_DATA__") {
+			if let Ok(value) = serde_json::from_str(&content) {
+				payloads.push(value);
+			}
+			continue;
+		}
+		payloads.extend(rsc_payloads(&content));
+	}
+
+	payloads
+}
+
+// JSON chunks pushed by the `self.__next_f.push([id, "id:json"])` calls of a script
+fn rsc_payloads(script: &str) -> Vec<serde_json::Value> {
+	let mut payloads = Vec::new();
+	let mut rest = script;
+	while let Some(start) = rest.find("self.__next_f.push(") {
+		rest = &rest[start + "self.__next_f.push(".len()..];
+		// Only the first value, the call continues with `)`
+		let Some(Ok(push)) = serde_json::Deserializer::from_str(rest).into_iter::<serde_json::Value>().next() else {
+			continue;
+		};
+		let Some(chunk) = push.get(1).and_then(|v| v.as_str()) else {
+			continue;
+		};
+		let json = chunk.split_once(':').map(|(_, json)| json).unwrap_or(chunk);
+		if let Ok(value) = serde_json::from_str(json) {
+			payloads.push(value);
+		}
+	}
+	payloads
+}
+
+// Collects the series of the "related"/"similar"/"recommended" arrays of a payload
+fn collect_related(value: &serde_json::Value, depth: usize, items: &mut Vec<MangaItem>) {
+	if depth > 12 {
+		return;
+	}
+	match value {
+		serde_json::Value::Object(object) => {
+			for (key, nested) in object {
+				let key = key.to_lowercase();
+				let is_related = ["related", "similar", "recommend"].iter().any(|word| key.contains(word));
+				match nested.as_array() {
+					Some(array) if is_related => items.extend(
+						array.iter().filter_map(|item| serde_json::from_value::<MangaItem>(item.clone()).ok()),
+					),
+					_ => collect_related(nested, depth + 1, items),
+				}
+			}
+		}
+		serde_json::Value::Array(array) => {
+			for nested in array {
+				collect_related(nested, depth + 1, items);
+			}
+		}
+		_ => {}
+	}
+}
+
+/// "Séries similaires" of a series page, from the series objects (`slug`,
+/// `title`, like the API ones) of its Next.js data, without the series itself.
+fn parse_related_manga(manga_key: &str, html: &Document) -> Vec<Manga> {
+	let mut items = Vec::new();
+	for payload in next_payloads(html) {
+		collect_related(&payload, 0, &mut items);
+	}
+
+	let mut mangas: Vec<Manga> = Vec::new();
+	for item in items {
+		if item.slug.is_empty() || item.title.trim().is_empty() || item.slug == manga_key {
+			continue;
+		}
+		if mangas.iter().any(|manga| manga.key == item.slug) {
+			continue;
+		}
+		mangas.push(item.to_manga());
+	}
+	mangas
+}
+
 // Parse the /series HTML page to extract manga list
 pub fn parse_series_page(html: &Document) -> Result<MangaPageResult> {
 	let mut mangas: Vec<Manga> = Vec::new();
@@ -1675,3 +1770,87 @@ pub fn parse_series_page(html: &Document) -> Result<MangaPageResult> {
 		has_next_page,
 	})
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aidoku::imports::html::Html;
+	use aidoku_test::aidoku_test;
+
+	// A premium chapter as the series page payload lists it
+	const PREMIUM_CHAPTER: &str = r#"{
+		"id": 4821,
+		"number": 112,
+		"title": null,
+		"isPremium": true,
+		"createdAt": "$D2025-10-17T16:00:00.000Z",
+		"premiumUntil": "$D2025-10-24T16:00:00.000Z"
+	}"#;
+
+	#[test]
+	fn release_date_comes_from_premium_until() {
+		let chapter: serde_json::Value = serde_json::from_str(PREMIUM_CHAPTER).unwrap();
+		let date = public_release_date(&chapter);
+		assert_eq!(date, Some(1761321600));
+		assert_eq!(
+			early_access_title(Some("Chapitre 112"), 112.0, date),
+			"Chapitre 112 (accès anticipé, public le 24/10/2025)"
+		);
+	}
+
+	#[test]
+	fn release_date_is_unknown_without_premium_until() {
+		let chapter = serde_json::json!({ "number": 112, "isPremium": true, "publicAt": "2025-10-24T16:00:00Z" });
+		assert_eq!(public_release_date(&chapter), None);
+		assert_eq!(early_access_title(None, 112.0, None), "Chapitre 112 (accès anticipé)");
+	}
+
+	#[test]
+	fn early_access_label_follows_the_chapter_title() {
+		assert_eq!(
+			early_access_title(Some("Chapitre 112 - Le retour du roi"), 112.0, Some(1761321600)),
+			"Chapitre 112 - Le retour du roi (accès anticipé, public le 24/10/2025)"
+		);
+		assert_eq!(early_access_title(Some("  "), 112.5, None), "Chapitre 112.5 (accès anticipé)");
+	}
+
+	// Chunks of the RSC stream of a series page
+	const SERIES_SCRIPT: &str = r#"self.__next_f.push([1,"a:[\"$\",\"div\",null,{}]\n"])
+self.__next_f.push([1,"b:{\"manga\":{\"slug\":\"solo-leveling\",\"title\":\"Solo Leveling\",\"relatedSeries\":[{\"slug\":\"the-beginning-after-the-end\",\"title\":\"The Beginning After the End\",\"status\":\"en cours\"},{\"slug\":\"solo-leveling\",\"title\":\"Solo Leveling\"}]}}"])
+self.__next_f.push([1,"c:{\"recommendations\":{\"similarManga\":[{\"slug\":\"omniscient-reader\",\"title\":\"Omniscient Reader\"},{\"id\":3}]},\"chapters\":[{\"number\":1,\"title\":\"Chapitre 1\"}]}"])"#;
+
+	#[test]
+	fn reads_rsc_chunks() {
+		let payloads = rsc_payloads(SERIES_SCRIPT);
+		assert_eq!(payloads.len(), 3);
+		assert_eq!(payloads[0][1], "div");
+		assert_eq!(payloads[1]["manga"]["slug"], "solo-leveling");
+		assert!(rsc_payloads("self.__next_f.push([1,\"not json\"])").is_empty());
+	}
+
+	#[test]
+	fn collects_related_series_at_any_depth() {
+		let mut items = Vec::new();
+		for payload in rsc_payloads(SERIES_SCRIPT) {
+			collect_related(&payload, 0, &mut items);
+		}
+		let slugs: Vec<&str> = items.iter().map(|item| item.slug.as_str()).collect();
+		// Chapters aren't series, and objects without a slug are skipped
+		assert_eq!(slugs, ["the-beginning-after-the-end", "solo-leveling", "omniscient-reader"]);
+		assert_eq!(items[0].status.as_deref(), Some("en cours"));
+	}
+
+	#[aidoku_test]
+	fn related_series_skip_the_series_itself() {
+		let html = Html::parse(format!(
+			"<html><body><script>{}</script><script>self.__next_f.push([1,\"d:{{\\\"related\\\":[{{\\\"slug\\\":\\\"omniscient-reader\\\",\\\"title\\\":\\\"Omniscient Reader\\\"}}]}}\"])</script></body></html>",
+			SERIES_SCRIPT
+		))
+		.unwrap();
+		let related: Vec<String> = parse_related_manga("solo-leveling", &html)
+			.into_iter()
+			.map(|manga| manga.key)
+			.collect();
+		assert_eq!(related, ["the-beginning-after-the-end", "omniscient-reader"]);
+	}
+}
@@ -93,17 +93,7 @@ impl Source for RaijinScans {
 			String::new()
 		};
 
-		let mut url = if page == 1 {
-			format!(
-				"{}/?post_type=wp-manga&s={}&sort={}",
-				BASE_URL, encoded_query, sort_filter
-			)
-		} else {
-			format!(
-				"{}/page/{}/?post_type=wp-manga&s={}&sort={}",
-				BASE_URL, page, encoded_query, sort_filter
-			)
-		};
+		let mut url = Self::build_search_url(&encoded_query, &sort_filter, page);
 
 		Self::append_filter_params(&mut url, &genre_filters, "genre");
 		Self::append_filter_params(&mut url, &status_filters, "status");
@@ -170,6 +160,9 @@ impl ListingProvider for RaijinScans {
 		match listing.id.as_str() {
 			"popular" => self.get_popular_manga(page),
 			"latest" => self.get_latest_manga(page),
+			// "Récent" option of the search page's sort filter (res/filters.json),
+			// also the default sort of searches
+			"nouveautes" => self.get_sorted_manga("recently_added", page),
 			_ => self.get_latest_manga(page),
 		}
 	}
@@ -216,6 +209,32 @@ impl RaijinScans {
 		}
 	}
 
+	fn build_search_url(encoded_query: &str, sort: &str, page: i32) -> String {
+		if page == 1 {
+			format!(
+				"{}/?post_type=wp-manga&s={}&sort={}",
+				BASE_URL, encoded_query, sort
+			)
+		} else {
+			format!(
+				"{}/page/{}/?post_type=wp-manga&s={}&sort={}",
+				BASE_URL, page, encoded_query, sort
+			)
+		}
+	}
+
+	/// Whole catalogue through the search page, sorted with one of the `sort`
+	/// filter values (`recently_added`, `title_az`...)
+	fn get_sorted_manga(&self, sort: &str, page: i32) -> Result<MangaPageResult> {
+		let url = Self::build_search_url("", sort, page);
+		let html = Self::create_html_request(&url)?;
+
+		Ok(MangaPageResult {
+			entries: self.parse_search_results(&html),
+			has_next_page: has_next_page(&html),
+		})
+	}
+
 	fn append_filter_params(url: &mut String, filters: &[String], param_name: &str) {
 		for filter in filters {
 			url.push_str(&format!("&{}%5B%5D={}", param_name, filter));
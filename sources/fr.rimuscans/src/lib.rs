@@ -163,9 +163,9 @@ impl Source for RimuScans {
 impl ListingProvider for RimuScans {
 	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
 		match listing.id.as_str() {
-			"popular" => self.get_popular_manga(page),
-			"latest" => self.get_latest_manga(page),
-			_ => self.get_latest_manga(page),
+			"popular" => self.get_manga_list_by_order("popular", page),
+			"nouveautes" => self.get_manga_list_by_order("latest", page),
+			_ => self.get_manga_list_by_order("update", page),
 		}
 	}
 }
@@ -207,22 +207,10 @@ impl RimuScans {
 		}
 	}
 
-	fn get_popular_manga(&self, page: i32) -> Result<MangaPageResult> {
-		let url = Self::build_listing_url("popular", page);
-
-		let html = Self::create_html_request(&url)?;
-
-		let mangas = parse_manga_list(&html, BASE_URL);
-		let has_more = has_next_page(&html);
-
-		Ok(MangaPageResult {
-			entries: mangas,
-			has_next_page: has_more,
-		})
-	}
-
-	fn get_latest_manga(&self, page: i32) -> Result<MangaPageResult> {
-		let url = Self::build_listing_url("update", page);
+	/// Catalogue sorted with one of the theme's `order` values: `popular`,
+	/// `update` (latest chapters) or `latest` (recently added series).
+	fn get_manga_list_by_order(&self, order: &str, page: i32) -> Result<MangaPageResult> {
+		let url = Self::build_listing_url(order, page);
 
 		let html = Self::create_html_request(&url)?;
 
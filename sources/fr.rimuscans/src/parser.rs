@@ -391,11 +391,27 @@ pub fn parse_page_list(html: &Document) -> Vec<Page> {
 }
 
 pub fn has_next_page(html: &Document) -> bool {
-	if let Some(voir_plus) = html.select("div.hpage a.r") {
-		if !voir_plus.is_empty() {
+	if let Some(next_links) = html.select("div.hpage a.r, .pagination a.next") {
+		if !next_links.is_empty() {
 			return true;
 		}
 	}
 
-	false
+	// Numbered pagination without a "next" link: compare the current page
+	// with the highest page listed
+	let page_number = |selector: &str| {
+		html.select(selector).and_then(|elements| {
+			elements
+				.filter_map(|element| element.text())
+				.filter_map(|text| text.trim().parse::<i32>().ok())
+				.max()
+		})
+	};
+	match (
+		page_number(".pagination .current"),
+		page_number(".pagination a.page-numbers"),
+	) {
+		(Some(current), Some(last)) => last > current,
+		_ => false,
+	}
 }
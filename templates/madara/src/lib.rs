@@ -1,10 +1,21 @@
 #![no_std]
 
+pub mod load_more;
+pub mod protector;
+pub mod related;
+pub mod scanlators;
+
+#[cfg(feature = "legacy")]
 pub mod template;
+#[cfg(feature = "legacy")]
 pub mod helper;
+#[cfg(feature = "legacy")]
 pub mod types;
 
 // Re-export everything from the template and helper modules
+#[cfg(feature = "legacy")]
 pub use template::*;
+#[cfg(feature = "legacy")]
 pub use helper::*;
-pub use types::*;
\ No newline at end of file
+#[cfg(feature = "legacy")]
+pub use types::*;
@@ -0,0 +1,95 @@
+//! Pagination for the Madara `madara_load_more` AJAX listing.
+//!
+//! `wp-admin/admin-ajax.php` answers with the bare `.page-item-detail` items of
+//! the requested page. Past the last page the body is empty, and the last page
+//! holds fewer than `posts_per_page` items. Regular archive pages (`/manga/?page=N`)
+//! render a `navigation-ajax` "load more" button instead, but only while there
+//! are more posts to load.
+
+extern crate alloc;
+use alloc::{format, string::String};
+
+/// Number of posts requested per page, sent as `posts_per_page` and `numberposts`.
+pub const POSTS_PER_PAGE: usize = 20;
+
+/// The "load more" button of archive pages, only rendered when a next page exists.
+pub const NAVIGATION_AJAX_SELECTOR: &str = "#navigation-ajax, .navigation-ajax";
+
+/// Alphabetical order.
+pub const ORDER_BY_TITLE: &str = "vars%5Borderby%5D=post_title&vars%5Border%5D=ASC";
+/// Most viewed first.
+pub const ORDER_BY_VIEWS: &str =
+	"vars%5Borderby%5D=meta_value_num&vars%5Bmeta_key%5D=_wp_manga_views&vars%5Border%5D=DESC";
+/// Trending first.
+pub const ORDER_BY_TRENDING: &str = "vars%5Borderby%5D=trending&vars%5Border%5D=DESC";
+
+/// Builds the form body of a `madara_load_more` request for the 1-based `page`.
+///
+/// `order` is one of the `ORDER_BY_*` constants; extra `vars[...]` parameters
+/// (search, taxonomy queries) can be appended to the returned body.
+pub fn load_more_body(page: i32, order: &str) -> String {
+	format!(
+		"action=madara_load_more&page={}&template=madara-core/content/content-archive&{}&vars%5Bpaged%5D={}&vars%5Btemplate%5D=archive&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Bmanga_archives_item_layout%5D=big_thumbnail&vars%5Bposts_per_page%5D={}&vars%5Bnumberposts%5D={}",
+		page - 1,
+		order,
+		page,
+		POSTS_PER_PAGE,
+		POSTS_PER_PAGE
+	)
+}
+
+/// Whether another page follows a listing page.
+///
+/// `item_count` is the number of manga items in the response, before any
+/// filtering, and `has_navigation` whether it contains the `navigation-ajax` marker.
+pub fn has_next_page(item_count: usize, has_navigation: bool) -> bool {
+	if item_count == 0 {
+		// Empty body: we are past the last page
+		return false;
+	}
+	has_navigation || item_count >= POSTS_PER_PAGE
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn full_page_has_next_page() {
+		assert!(has_next_page(POSTS_PER_PAGE, false));
+		assert!(has_next_page(POSTS_PER_PAGE, true));
+	}
+
+	#[test]
+	fn partial_page_is_the_last_one_without_navigation() {
+		assert!(!has_next_page(POSTS_PER_PAGE - 1, false));
+		assert!(!has_next_page(1, false));
+		assert!(has_next_page(1, true));
+	}
+
+	#[test]
+	fn empty_page_never_has_next_page() {
+		assert!(!has_next_page(0, false));
+		assert!(!has_next_page(0, true));
+	}
+
+	#[test]
+	fn body_is_zero_based_with_one_based_paged_var() {
+		let body = load_more_body(1, ORDER_BY_TITLE);
+		assert!(body.starts_with("action=madara_load_more&page=0&"));
+		assert!(body.contains("&vars%5Bpaged%5D=1&"));
+		assert!(body.contains(ORDER_BY_TITLE));
+
+		let body = load_more_body(3, ORDER_BY_VIEWS);
+		assert!(body.contains("&page=2&"));
+		assert!(body.contains("&vars%5Bpaged%5D=3&"));
+		assert!(body.contains(ORDER_BY_VIEWS));
+	}
+
+	#[test]
+	fn body_requests_posts_per_page_items() {
+		let body = load_more_body(1, ORDER_BY_TRENDING);
+		assert!(body.contains(&format!("vars%5Bposts_per_page%5D={}", POSTS_PER_PAGE)));
+		assert!(body.ends_with(&format!("vars%5Bnumberposts%5D={}", POSTS_PER_PAGE)));
+	}
+}
@@ -0,0 +1,562 @@
+//! Decoder for the Madara `chapter_protector` plugin.
+//!
+//! Protected chapters don't ship their images as `div.page-break img` tags.
+//! Instead the reader page embeds a `script#chapter-protector-data` holding a
+//! passphrase (`wpmangaprotectornonce`) and a CryptoJS AES payload
+//! (`chapter_data`), which decrypts to a JSON array of image URLs.
+//!
+//! CryptoJS uses OpenSSL's `EVP_BytesToKey` with MD5 to derive the key and IV
+//! from the passphrase and salt, then AES-256-CBC with PKCS#7 padding.
+//! Everything here only depends on `alloc` so it can be used from any source.
+
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+use base64::prelude::*;
+
+pub const CHAPTER_PROTECTOR_SELECTOR: &str = "script#chapter-protector-data";
+
+/// Returns the image URLs hidden in a `chapter-protector-data` script, or
+/// `None` if the script isn't a chapter protector or can't be decrypted.
+pub fn decode_chapter_protector(script: &str) -> Option<Vec<String>> {
+	let passphrase = extract_js_string(script, "wpmangaprotectornonce")?;
+	let chapter_data = extract_js_string(script, "chapter_data")?
+		.replace("\\/", "/")
+		.replace("\\\"", "\"");
+
+	let ciphertext = BASE64_STANDARD
+		.decode(extract_json_field(&chapter_data, "ct")?.as_bytes())
+		.ok()?;
+	let salt = decode_hex(&extract_json_field(&chapter_data, "s")?)?;
+
+	let plaintext = decrypt_cryptojs(&ciphertext, &salt, passphrase.as_bytes())?;
+	let plaintext = String::from_utf8(plaintext).ok()?;
+
+	// the image array is JSON encoded twice, once by the plugin and once by CryptoJS
+	let trimmed = plaintext.trim();
+	let array = if trimmed.starts_with('"') {
+		parse_json_string(trimmed)?.0
+	} else {
+		String::from(trimmed)
+	};
+
+	let urls = parse_json_string_array(&array);
+	if urls.is_empty() { None } else { Some(urls) }
+}
+
+/// Decodes a `data:text/javascript;base64,` script source, used by sites that
+/// inline the protector script through `src` instead of its body.
+pub fn decode_data_script(src: &str) -> Option<String> {
+	let encoded = src.strip_prefix("data:text/javascript;base64,")?;
+	let bytes = BASE64_STANDARD.decode(encoded.trim().as_bytes()).ok()?;
+	String::from_utf8(bytes).ok()
+}
+
+/// Image URLs of a `CHAPTER_PROTECTOR_SELECTOR` script from its body, or from
+/// its `src` data URL when some installs inline it there instead.
+pub fn protected_image_urls(body: Option<String>, src: Option<String>) -> Option<Vec<String>> {
+	let script = body
+		.filter(|content| !content.trim().is_empty())
+		.or_else(|| src.and_then(|src| decode_data_script(&src)))?;
+	decode_chapter_protector(&script)
+}
+
+/// Decrypts a salted CryptoJS AES payload with the given passphrase.
+pub fn decrypt_cryptojs(ciphertext: &[u8], salt: &[u8], passphrase: &[u8]) -> Option<Vec<u8>> {
+	let (key, iv) = evp_bytes_to_key(passphrase, salt);
+	aes256_cbc_decrypt(&key, &iv, ciphertext)
+}
+
+/// OpenSSL `EVP_BytesToKey` with MD5 and a single iteration, producing a
+/// 32-byte key followed by a 16-byte IV.
+pub fn evp_bytes_to_key(passphrase: &[u8], salt: &[u8]) -> ([u8; 32], [u8; 16]) {
+	let mut derived: Vec<u8> = Vec::with_capacity(48);
+	let mut block: Vec<u8> = Vec::new();
+
+	while derived.len() < 48 {
+		let mut input = Vec::with_capacity(block.len() + passphrase.len() + salt.len());
+		input.extend_from_slice(&block);
+		input.extend_from_slice(passphrase);
+		input.extend_from_slice(salt);
+		block = md5(&input).to_vec();
+		derived.extend_from_slice(&block);
+	}
+
+	let mut key = [0u8; 32];
+	let mut iv = [0u8; 16];
+	key.copy_from_slice(&derived[..32]);
+	iv.copy_from_slice(&derived[32..48]);
+	(key, iv)
+}
+
+fn extract_js_string(script: &str, name: &str) -> Option<String> {
+	let start = script.find(name)? + name.len();
+	let rest = script[start..].trim_start();
+	let rest = rest.strip_prefix('=')?.trim_start();
+	let quote = rest.chars().next()?;
+	if quote != '\'' && quote != '"' {
+		return None;
+	}
+	let value = &rest[1..];
+	let end = value.find(quote)?;
+	Some(String::from(&value[..end]))
+}
+
+fn extract_json_field(json: &str, field: &str) -> Option<String> {
+	let needle = alloc::format!("\"{}\"", field);
+	let start = json.find(&needle)? + needle.len();
+	let rest = json[start..].trim_start().strip_prefix(':')?.trim_start();
+	parse_json_string(rest).map(|(value, _)| value)
+}
+
+/// Parses the JSON string literal at the start of `input`, returning the
+/// unescaped value and the number of bytes consumed.
+fn parse_json_string(input: &str) -> Option<(String, usize)> {
+	let mut chars = input.char_indices();
+	if chars.next()?.1 != '"' {
+		return None;
+	}
+
+	let mut result = String::new();
+	while let Some((index, ch)) = chars.next() {
+		match ch {
+			'"' => return Some((result, index + 1)),
+			'\\' => match chars.next()?.1 {
+				'n' => result.push('\n'),
+				't' => result.push('\t'),
+				'r' => result.push('\r'),
+				'b' => result.push('\u{8}'),
+				'f' => result.push('\u{c}'),
+				'u' => {
+					let mut code = 0u32;
+					for _ in 0..4 {
+						code = code * 16 + chars.next()?.1.to_digit(16)?;
+					}
+					result.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+				}
+				other => result.push(other),
+			},
+			_ => result.push(ch),
+		}
+	}
+	None
+}
+
+fn parse_json_string_array(input: &str) -> Vec<String> {
+	let mut values = Vec::new();
+	let mut rest = input.trim().strip_prefix('[').unwrap_or("");
+
+	while let Some(start) = rest.find('"') {
+		match parse_json_string(&rest[start..]) {
+			Some((value, consumed)) => {
+				let value = String::from(value.trim());
+				if !value.is_empty() {
+					values.push(value);
+				}
+				rest = &rest[start + consumed..];
+			}
+			None => break,
+		}
+	}
+	values
+}
+
+fn decode_hex(input: &str) -> Option<Vec<u8>> {
+	let input = input.trim();
+	if input.len() % 2 != 0 {
+		return None;
+	}
+	input
+		.as_bytes()
+		.chunks(2)
+		.map(|pair| {
+			let high = (pair[0] as char).to_digit(16)?;
+			let low = (pair[1] as char).to_digit(16)?;
+			Some((high * 16 + low) as u8)
+		})
+		.collect()
+}
+
+// MD5 (RFC 1321)
+
+const MD5_SHIFTS: [u32; 64] = [
+	7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22,
+	5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20,
+	4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23,
+	6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+const MD5_CONSTANTS: [u32; 64] = [
+	0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee,
+	0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+	0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be,
+	0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+	0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa,
+	0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+	0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed,
+	0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+	0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c,
+	0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+	0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05,
+	0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+	0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039,
+	0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+	0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1,
+	0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+pub fn md5(data: &[u8]) -> [u8; 16] {
+	let mut state: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+	let mut message = data.to_vec();
+	let bit_len = (data.len() as u64).wrapping_mul(8);
+	message.push(0x80);
+	while message.len() % 64 != 56 {
+		message.push(0);
+	}
+	message.extend_from_slice(&bit_len.to_le_bytes());
+
+	for chunk in message.chunks(64) {
+		let mut words = [0u32; 16];
+		for (i, word) in words.iter_mut().enumerate() {
+			*word = u32::from_le_bytes([
+				chunk[i * 4],
+				chunk[i * 4 + 1],
+				chunk[i * 4 + 2],
+				chunk[i * 4 + 3],
+			]);
+		}
+
+		let [mut a, mut b, mut c, mut d] = state;
+		for i in 0..64 {
+			let (f, g) = match i / 16 {
+				0 => ((b & c) | (!b & d), i),
+				1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+				2 => (b ^ c ^ d, (3 * i + 5) % 16),
+				_ => (c ^ (b | !d), (7 * i) % 16),
+			};
+			let rotated = a
+				.wrapping_add(f)
+				.wrapping_add(MD5_CONSTANTS[i])
+				.wrapping_add(words[g])
+				.rotate_left(MD5_SHIFTS[i]);
+			a = d;
+			d = c;
+			c = b;
+			b = b.wrapping_add(rotated);
+		}
+
+		state[0] = state[0].wrapping_add(a);
+		state[1] = state[1].wrapping_add(b);
+		state[2] = state[2].wrapping_add(c);
+		state[3] = state[3].wrapping_add(d);
+	}
+
+	let mut digest = [0u8; 16];
+	for (i, word) in state.iter().enumerate() {
+		digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+	}
+	digest
+}
+
+// AES-256 (FIPS 197), decryption only
+
+const AES_ROUNDS: usize = 14;
+
+const SBOX: [u8; 256] = [
+	0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+	0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+	0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+	0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+	0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+	0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+	0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+	0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+	0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+	0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+	0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+	0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+	0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+	0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+	0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+	0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+fn inv_sbox() -> [u8; 256] {
+	let mut inverse = [0u8; 256];
+	for (i, value) in SBOX.iter().enumerate() {
+		inverse[*value as usize] = i as u8;
+	}
+	inverse
+}
+
+fn xtime(value: u8) -> u8 {
+	if value & 0x80 != 0 {
+		(value << 1) ^ 0x1b
+	} else {
+		value << 1
+	}
+}
+
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+	let mut result = 0;
+	while b != 0 {
+		if b & 1 != 0 {
+			result ^= a;
+		}
+		a = xtime(a);
+		b >>= 1;
+	}
+	result
+}
+
+fn expand_key(key: &[u8; 32]) -> [[u8; 16]; AES_ROUNDS + 1] {
+	let mut words = [[0u8; 4]; 4 * (AES_ROUNDS + 1)];
+	for (i, word) in words.iter_mut().take(8).enumerate() {
+		word.copy_from_slice(&key[i * 4..i * 4 + 4]);
+	}
+
+	let mut rcon: u8 = 1;
+	for i in 8..words.len() {
+		let mut temp = words[i - 1];
+		if i % 8 == 0 {
+			temp.rotate_left(1);
+			for byte in temp.iter_mut() {
+				*byte = SBOX[*byte as usize];
+			}
+			temp[0] ^= rcon;
+			rcon = xtime(rcon);
+		} else if i % 8 == 4 {
+			for byte in temp.iter_mut() {
+				*byte = SBOX[*byte as usize];
+			}
+		}
+		for j in 0..4 {
+			words[i][j] = words[i - 8][j] ^ temp[j];
+		}
+	}
+
+	let mut round_keys = [[0u8; 16]; AES_ROUNDS + 1];
+	for (round, round_key) in round_keys.iter_mut().enumerate() {
+		for j in 0..4 {
+			round_key[j * 4..j * 4 + 4].copy_from_slice(&words[round * 4 + j]);
+		}
+	}
+	round_keys
+}
+
+fn decrypt_block(block: &mut [u8; 16], round_keys: &[[u8; 16]; AES_ROUNDS + 1], inv_sbox: &[u8; 256]) {
+	add_round_key(block, &round_keys[AES_ROUNDS]);
+	for round in (0..AES_ROUNDS).rev() {
+		inv_shift_rows(block);
+		for byte in block.iter_mut() {
+			*byte = inv_sbox[*byte as usize];
+		}
+		add_round_key(block, &round_keys[round]);
+		if round > 0 {
+			inv_mix_columns(block);
+		}
+	}
+}
+
+fn add_round_key(block: &mut [u8; 16], round_key: &[u8; 16]) {
+	for (byte, key) in block.iter_mut().zip(round_key.iter()) {
+		*byte ^= key;
+	}
+}
+
+fn inv_shift_rows(block: &mut [u8; 16]) {
+	// the state is column-major: byte (row, col) lives at col * 4 + row
+	let copy = *block;
+	for row in 1..4 {
+		for col in 0..4 {
+			block[((col + row) % 4) * 4 + row] = copy[col * 4 + row];
+		}
+	}
+}
+
+fn inv_mix_columns(block: &mut [u8; 16]) {
+	for col in 0..4 {
+		let a = [
+			block[col * 4],
+			block[col * 4 + 1],
+			block[col * 4 + 2],
+			block[col * 4 + 3],
+		];
+		block[col * 4] = gf_mul(a[0], 14) ^ gf_mul(a[1], 11) ^ gf_mul(a[2], 13) ^ gf_mul(a[3], 9);
+		block[col * 4 + 1] = gf_mul(a[0], 9) ^ gf_mul(a[1], 14) ^ gf_mul(a[2], 11) ^ gf_mul(a[3], 13);
+		block[col * 4 + 2] = gf_mul(a[0], 13) ^ gf_mul(a[1], 9) ^ gf_mul(a[2], 14) ^ gf_mul(a[3], 11);
+		block[col * 4 + 3] = gf_mul(a[0], 11) ^ gf_mul(a[1], 13) ^ gf_mul(a[2], 9) ^ gf_mul(a[3], 14);
+	}
+}
+
+/// AES-256-CBC decryption with PKCS#7 padding removal.
+pub fn aes256_cbc_decrypt(key: &[u8; 32], iv: &[u8; 16], data: &[u8]) -> Option<Vec<u8>> {
+	if data.is_empty() || data.len() % 16 != 0 {
+		return None;
+	}
+
+	let round_keys = expand_key(key);
+	let inv_sbox = inv_sbox();
+
+	let mut output = Vec::with_capacity(data.len());
+	let mut previous = *iv;
+	for chunk in data.chunks(16) {
+		let mut block = [0u8; 16];
+		block.copy_from_slice(chunk);
+		let cipher_block = block;
+
+		decrypt_block(&mut block, &round_keys, &inv_sbox);
+		for (byte, prev) in block.iter_mut().zip(previous.iter()) {
+			*byte ^= prev;
+		}
+		output.extend_from_slice(&block);
+		previous = cipher_block;
+	}
+
+	let padding = *output.last()? as usize;
+	if padding == 0 || padding > 16 || output[output.len() - padding..].iter().any(|b| *b as usize != padding) {
+		return None;
+	}
+	output.truncate(output.len() - padding);
+	Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::format;
+
+	fn hex(input: &str) -> Vec<u8> {
+		decode_hex(input).unwrap()
+	}
+
+	fn to_hex(bytes: &[u8]) -> String {
+		bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+	}
+
+	// RFC 1321, appendix A.5
+	#[test]
+	fn md5_rfc1321_test_suite() {
+		let vectors = [
+			("", "d41d8cd98f00b204e9800998ecf8427e"),
+			("a", "0cc175b9c0f1b6a831c399e269772661"),
+			("abc", "900150983cd24fb0d6963f7d28e17f72"),
+			("message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+			("abcdefghijklmnopqrstuvwxyz", "c3fcd3d76192e4007dfb496cca67e13b"),
+			(
+				"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+				"d174ab98d277d9f5a5611c2c9f419d9f",
+			),
+			(
+				"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+				"57edf4a22be3c955ac49da2e2107b67a",
+			),
+		];
+		for (input, digest) in vectors {
+			assert_eq!(to_hex(&md5(input.as_bytes())), digest, "md5({:?})", input);
+		}
+	}
+
+	// FIPS-197, appendix C.3 (AES-256)
+	#[test]
+	fn aes256_fips197_block() {
+		let key: [u8; 32] = hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+			.try_into()
+			.unwrap();
+		let mut block: [u8; 16] = hex("8ea2b7ca516745bfeafc49904b496089").try_into().unwrap();
+
+		decrypt_block(&mut block, &expand_key(&key), &inv_sbox());
+		assert_eq!(to_hex(&block), "00112233445566778899aabbccddeeff");
+	}
+
+	// NIST SP 800-38A, F.2.6 (CBC-AES256.Decrypt), followed by the PKCS#7
+	// padding block openssl appends to the 64-byte plaintext
+	#[test]
+	fn aes256_cbc_nist_sp800_38a() {
+		let key: [u8; 32] = hex("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+			.try_into()
+			.unwrap();
+		let iv: [u8; 16] = hex("000102030405060708090a0b0c0d0e0f").try_into().unwrap();
+		let ciphertext = hex(concat!(
+			"f58c4c04d6e5f1ba779eabfb5f7bfbd6",
+			"9cfc4e967edb808d679f777bc6702c7d",
+			"39f23369a9d9bacfa530e26304231461",
+			"b2eb05e2c39be9fcda6c19078c6a9d1b",
+			"3f461796d6b0d6b2e0c2a72b4d80e644",
+		));
+
+		let plaintext = aes256_cbc_decrypt(&key, &iv, &ciphertext).unwrap();
+		assert_eq!(
+			to_hex(&plaintext),
+			concat!(
+				"6bc1bee22e409f96e93d7e117393172a",
+				"ae2d8a571e03ac9c9eb76fac45af8e51",
+				"30c81c46a35ce411e5fbc1191a0a52ef",
+				"f69f2445df4f9b17ad2b417be66c3710",
+			)
+		);
+	}
+
+	#[test]
+	fn aes256_cbc_rejects_bad_input() {
+		let key = [0u8; 32];
+		let iv = [0u8; 16];
+		assert_eq!(aes256_cbc_decrypt(&key, &iv, &[]), None);
+		assert_eq!(aes256_cbc_decrypt(&key, &iv, &[0u8; 15]), None);
+	}
+
+	// `openssl enc -aes-256-cbc -md md5 -S 5990759954e29def -pass pass:a1b2c3d4e5 -P`
+	#[test]
+	fn evp_bytes_to_key_matches_openssl() {
+		let (key, iv) = evp_bytes_to_key(b"a1b2c3d4e5", &hex("5990759954e29def"));
+		assert_eq!(to_hex(&key), "c8b14599aa63ca363335d182b31637381738a14f39965b327ad036afb4173a11");
+		assert_eq!(to_hex(&iv), "22d1cbb25eea487aab72b2c66f10efb0");
+	}
+
+	// Produced by `openssl enc -aes-256-cbc -md md5 -pass pass:a1b2c3d4e5 -base64`
+	// from the JSON encoded image array, as CryptoJS does
+	const OPENSSL_PAYLOAD: &str = "U2FsdGVkX19ZkHWZVOKd7+I4q016upnp5KBkF/ZwbPNMDQs4qIGVwbr4gW85Dtg5/MtNvnLgg1Jjt9O8dlc9hszqnRNVMYkN91jGlV4e1RCOtkN+eRkHhg8MdSZQxUjBFr6stLYynzQErWjK8C+WtAfzwCIhIQwkxDKv18ONglY=";
+
+	fn openssl_script() -> String {
+		let payload = BASE64_STANDARD.decode(OPENSSL_PAYLOAD).unwrap();
+		let (header, rest) = payload.split_at(16);
+		assert_eq!(&header[..8], b"Salted__");
+		let salt = to_hex(&header[8..]);
+		let ct = BASE64_STANDARD.encode(rest);
+
+		format!(
+			"var wpmangaprotectornonce = 'a1b2c3d4e5';\nvar chapter_data = '{{\\\"ct\\\":\\\"{}\\\",\\\"iv\\\":\\\"22d1cbb25eea487aab72b2c66f10efb0\\\",\\\"s\\\":\\\"{}\\\"}}';",
+			ct.replace('/', "\\/"),
+			salt
+		)
+	}
+
+	fn openssl_images() -> Vec<String> {
+		alloc::vec![
+			String::from("https://example.com/wp-content/uploads/1.jpg"),
+			String::from("https://example.com/wp-content/uploads/2.jpg"),
+		]
+	}
+
+	#[test]
+	fn decodes_openssl_salted_payload() {
+		assert_eq!(decode_chapter_protector(&openssl_script()), Some(openssl_images()));
+	}
+
+	#[test]
+	fn reads_the_script_body_or_its_data_url() {
+		let script = openssl_script();
+		let data_url = format!("data:text/javascript;base64,{}", BASE64_STANDARD.encode(&script));
+
+		assert_eq!(protected_image_urls(Some(script.clone()), None), Some(openssl_images()));
+		assert_eq!(protected_image_urls(Some(String::from("  ")), Some(data_url.clone())), Some(openssl_images()));
+		assert_eq!(protected_image_urls(None, Some(data_url)), Some(openssl_images()));
+		assert_eq!(protected_image_urls(None, Some(String::from("/wp-content/plugins/protector.js"))), None);
+	}
+
+	#[test]
+	fn wrong_passphrase_does_not_decode() {
+		let script = "var wpmangaprotectornonce = 'wrong';\nvar chapter_data = '{\\\"ct\\\":\\\"AAAAAAAAAAAAAAAAAAAAAA==\\\",\\\"s\\\":\\\"5990759954e29def\\\"}';";
+		assert_eq!(decode_chapter_protector(script), None);
+	}
+}
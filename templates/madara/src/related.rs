@@ -0,0 +1,77 @@
+//! "Séries similaires" block of Madara series pages.
+//!
+//! Series pages end with a `div.related-manga` block of `.related-reading-wrap`
+//! cards, each holding a thumbnail link and an `h5.widget-title` title link.
+//! Sources list their titles in the description of the series.
+
+extern crate alloc;
+use alloc::{string::{String, ToString}, vec::Vec};
+
+/// One related series card.
+pub const RELATED_MANGA_SELECTOR: &str = "div.related-manga .related-reading-wrap";
+
+/// Title link of a related series card.
+pub const RELATED_TITLE_SELECTOR: &str = "h5.widget-title a, .related-reading-content a";
+
+/// A related series card.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatedSeries {
+	pub key: String,
+	pub title: String,
+	pub url: String,
+	pub cover: Option<String>,
+}
+
+/// Related series from the `(href, title, cover)` of the cards, in page order.
+///
+/// `manga_key` turns a series URL into its key. Cards without a key or a title
+/// are skipped, as are cards repeating an earlier series.
+pub fn related_series<I>(cards: I, manga_key: impl Fn(&str) -> String) -> Vec<RelatedSeries>
+where
+	I: IntoIterator<Item = (String, String, Option<String>)>,
+{
+	let mut series: Vec<RelatedSeries> = Vec::new();
+	for (href, title, cover) in cards {
+		let key = manga_key(&href);
+		let title = title.trim();
+		if key.is_empty() || title.is_empty() || series.iter().any(|entry| entry.key == key) {
+			continue;
+		}
+		series.push(RelatedSeries {
+			key,
+			title: title.to_string(),
+			url: href,
+			cover,
+		});
+	}
+	series
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+
+	fn slug(href: &str) -> String {
+		href.trim_end_matches('/').rsplit('/').next().unwrap_or_default().to_string()
+	}
+
+	#[test]
+	fn skips_untitled_and_repeated_cards() {
+		let card = |href: &str, title: &str| (href.to_string(), title.to_string(), None);
+		let series = related_series(
+			vec![
+				card("https://example.fr/manga/a/", " A "),
+				card("https://example.fr/manga/b/", ""),
+				card("https://example.fr/manga/a/", "A again"),
+				card("", "No link"),
+				card("https://example.fr/manga/c/", "C"),
+			],
+			slug,
+		);
+		let keys: Vec<&str> = series.iter().map(|entry| entry.key.as_str()).collect();
+		assert_eq!(keys, vec!["a", "c"]);
+		assert_eq!(series[0].title, "A");
+		assert_eq!(series[0].url, "https://example.fr/manga/a/");
+	}
+}
@@ -0,0 +1,128 @@
+//! Scanlation teams credited on Madara series pages.
+//!
+//! Chapter rows sometimes name their team next to the release date. Otherwise
+//! the series summary (`div.post-content_item` rows of a `div.summary-heading`
+//! and a `div.summary-content`) lists the teams of the whole series, which then
+//! apply to every chapter that doesn't credit its own.
+
+extern crate alloc;
+use alloc::{string::{String, ToString}, vec::Vec};
+
+/// Team names inside a chapter row.
+pub const CHAPTER_TEAM_SELECTOR: &str =
+	".chapter-team, .chapter-scanlator, .team-name, span.chapter-release-date a[href*='team']";
+
+/// One row of the series summary.
+pub const SUMMARY_ITEM_SELECTOR: &str = "div.post-content_item";
+/// Heading of a summary row.
+pub const SUMMARY_HEADING_SELECTOR: &str = "div.summary-heading";
+/// Value of a summary row.
+pub const SUMMARY_CONTENT_SELECTOR: &str = "div.summary-content";
+
+const TEAM_HEADING_KEYWORDS: [&str; 6] = ["team", "équipe", "equipe", "traduct", "scantrad", "groupe"];
+
+/// Whether a summary heading ("Team", "Équipe de traduction"...) lists teams.
+pub fn is_team_heading(heading: &str) -> bool {
+	let heading = heading.to_lowercase();
+	TEAM_HEADING_KEYWORDS.iter().any(|keyword| heading.contains(keyword))
+}
+
+fn push_name(teams: &mut Vec<String>, name: &str) {
+	let name = name.trim();
+	if !name.is_empty() && !teams.iter().any(|team| team == name) {
+		teams.push(name.to_string());
+	}
+}
+
+/// Teams of a chapter row from the text of its `CHAPTER_TEAM_SELECTOR` elements.
+pub fn chapter_scanlators<I, S>(names: I) -> Option<Vec<String>>
+where
+	I: IntoIterator<Item = S>,
+	S: AsRef<str>,
+{
+	let mut teams = Vec::new();
+	for name in names {
+		push_name(&mut teams, name.as_ref());
+	}
+	if teams.is_empty() { None } else { Some(teams) }
+}
+
+/// Teams of a series from the `(heading, value)` pairs of its summary rows.
+/// Values list several teams separated by commas.
+pub fn series_scanlators<I, H, V>(items: I) -> Option<Vec<String>>
+where
+	I: IntoIterator<Item = (H, V)>,
+	H: AsRef<str>,
+	V: AsRef<str>,
+{
+	let mut teams = Vec::new();
+	for (heading, value) in items {
+		if !is_team_heading(heading.as_ref()) {
+			continue;
+		}
+		for name in value.as_ref().split(',') {
+			push_name(&mut teams, name);
+		}
+	}
+	if teams.is_empty() { None } else { Some(teams) }
+}
+
+/// Gives `default_teams` to the chapters whose `scanlators` are still unset.
+pub fn fill_default_scanlators<'a, I>(scanlators: I, default_teams: Option<Vec<String>>)
+where
+	I: IntoIterator<Item = &'a mut Option<Vec<String>>>,
+{
+	if let Some(default_teams) = default_teams {
+		for slot in scanlators.into_iter().filter(|slot| slot.is_none()) {
+			*slot = Some(default_teams.clone());
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+
+	#[test]
+	fn chapter_teams_are_trimmed_and_deduplicated() {
+		assert_eq!(
+			chapter_scanlators([" Team A ", "", "Team B", "Team A"]),
+			Some(vec!["Team A".to_string(), "Team B".to_string()])
+		);
+		assert_eq!(chapter_scanlators(["  "]), None);
+	}
+
+	#[test]
+	fn series_teams_come_from_team_headings() {
+		let items = [
+			("Statut", "En cours"),
+			("Équipe de traduction", "Team A, Team B"),
+			("Groupe", "Team B,Team C"),
+			("Auteur(s)", "Someone"),
+		];
+		assert_eq!(
+			series_scanlators(items),
+			Some(vec!["Team A".to_string(), "Team B".to_string(), "Team C".to_string()])
+		);
+		assert_eq!(series_scanlators([("Genre(s)", "Action")]), None);
+	}
+
+	#[test]
+	fn defaults_only_fill_unset_chapters() {
+		let mut chapters = vec![None, Some(vec!["Team A".to_string()]), None];
+		fill_default_scanlators(chapters.iter_mut(), Some(vec!["Team B".to_string()]));
+		assert_eq!(
+			chapters,
+			vec![
+				Some(vec!["Team B".to_string()]),
+				Some(vec!["Team A".to_string()]),
+				Some(vec!["Team B".to_string()]),
+			]
+		);
+
+		let mut chapters: Vec<Option<Vec<String>>> = vec![None];
+		fill_default_scanlators(chapters.iter_mut(), None);
+		assert_eq!(chapters, vec![None]);
+	}
+}
@@ -320,9 +320,11 @@ unsafe impl GlobalAlloc for DummyAllocator {
     }
 }
 
+#[cfg(not(test))]
 #[global_allocator]
 static GLOBAL: DummyAllocator = DummyAllocator;
 
+#[cfg(not(test))]
 #[panic_handler]
 fn panic(_info: &core::panic::PanicInfo) -> ! {
     loop {}
@@ -0,0 +1,372 @@
+use aidoku::{
+	ContentRating, MangaStatus, Viewer,
+	alloc::{String, Vec, format, string::ToString},
+};
+use chrono::NaiveDate;
+
+pub fn urlencode(string: &str) -> String {
+	let mut result: Vec<u8> = Vec::with_capacity(string.len() * 3);
+	let hex = "0123456789abcdef".as_bytes();
+
+	for &curr in string.as_bytes() {
+		if curr.is_ascii_alphanumeric() || matches!(curr, b'-' | b'_' | b'.' | b'~') {
+			result.push(curr);
+		} else if curr == b' ' {
+			result.push(b'+');
+		} else {
+			result.push(b'%');
+			result.push(hex[curr as usize >> 4]);
+			result.push(hex[curr as usize & 15]);
+		}
+	}
+
+	String::from_utf8(result).unwrap_or_default()
+}
+
+pub fn make_absolute_url(base_url: &str, url: &str) -> String {
+	if url.starts_with("http://") || url.starts_with("https://") {
+		url.to_string()
+	} else if url.starts_with("//") {
+		format!("https:{}", url)
+	} else if url.starts_with('/') {
+		format!("{}{}", base_url.trim_end_matches('/'), url)
+	} else {
+		format!("{}/{}", base_url.trim_end_matches('/'), url)
+	}
+}
+
+/// Path of a URL relative to the site root, without surrounding slashes.
+pub fn relative_path(base_url: &str, url: &str) -> String {
+	let without_host = match url.find("://") {
+		Some(scheme_end) => {
+			let rest = &url[scheme_end + 3..];
+			rest.find('/').map(|pos| &rest[pos..]).unwrap_or("")
+		}
+		None => url.strip_prefix(base_url).unwrap_or(url),
+	};
+	without_host
+		.split(['?', '#'])
+		.next()
+		.unwrap_or_default()
+		.trim_matches('/')
+		.to_string()
+}
+
+/// Layouts tried after the configured one: sites mix day-first and
+/// month-first dates, and some only show `dd/mm/yyyy`.
+const FALLBACK_DATE_FORMATS: [&str; 4] = ["%d %B %Y", "%B %d, %Y", "%B %d %Y", "%d/%m/%Y"];
+
+/// Parses a chapter date with a chrono format such as `%B %d, %Y`, then with
+/// `FALLBACK_DATE_FORMATS`.
+///
+/// French month names are translated first since chrono only knows the English
+/// ones, so `17 août 2025` and `janvier 12, 2024` parse as well.
+pub fn parse_date(text: &str, format: &str) -> Option<i64> {
+	let text = text.trim();
+	if text.is_empty() {
+		return None;
+	}
+
+	let normalized = text
+		.split_whitespace()
+		.map(|word| french_month_to_english(word).unwrap_or(word))
+		.collect::<Vec<_>>()
+		.join(" ");
+
+	core::iter::once(format)
+		.chain(FALLBACK_DATE_FORMATS.iter().copied().filter(|fallback| *fallback != format))
+		.find_map(|format| NaiveDate::parse_from_str(&normalized, format).ok())
+		.and_then(|date| date.and_hms_opt(0, 0, 0))
+		.map(|date| date.and_utc().timestamp())
+}
+
+fn french_month_to_english(word: &str) -> Option<&'static str> {
+	let month = match word.trim_end_matches([',', '.']).to_lowercase().as_str() {
+		"janvier" | "janv" => "January",
+		"février" | "fevrier" | "févr" => "February",
+		"mars" => "March",
+		"avril" | "avr" => "April",
+		"mai" => "May",
+		"juin" => "June",
+		"juillet" | "juil" => "July",
+		"août" | "aout" => "August",
+		"septembre" | "sept" => "September",
+		"octobre" | "oct" => "October",
+		"novembre" | "nov" => "November",
+		"décembre" | "decembre" | "déc" => "December",
+		_ => return None,
+	};
+	Some(month)
+}
+
+/// Default status mapping, covering the French and English MangaThemesia labels.
+pub fn default_status_mapping(status: &str) -> MangaStatus {
+	let status = status.trim().to_lowercase();
+	if status.contains("en cours") || status.contains("ongoing") || status.contains("partenaire") {
+		MangaStatus::Ongoing
+	} else if status.contains("termin") || status.contains("complet") || status.contains("fini") {
+		MangaStatus::Completed
+	} else if status.contains("abandon") || status.contains("annul") || status.contains("cancel") || status.contains("dropped") {
+		MangaStatus::Cancelled
+	} else if status.contains("pause") || status.contains("hiatus") {
+		MangaStatus::Hiatus
+	} else {
+		MangaStatus::Unknown
+	}
+}
+
+/// Default type mapping: long strip formats are read vertically.
+pub fn default_type_mapping(manga_type: &str) -> Option<Viewer> {
+	let manga_type = manga_type.trim().to_lowercase();
+	if manga_type.is_empty() {
+		None
+	} else if manga_type.contains("manhwa") || manga_type.contains("manhua") || manga_type.contains("webtoon") {
+		Some(Viewer::Vertical)
+	} else if manga_type.contains("comic") || manga_type == "bd" {
+		Some(Viewer::LeftToRight)
+	} else {
+		Some(Viewer::RightToLeft)
+	}
+}
+
+pub fn content_rating_from_tags(tags: &[String]) -> ContentRating {
+	let mut rating = ContentRating::Safe;
+	for tag in tags {
+		match tag.to_lowercase().as_str() {
+			"adult" | "adulte" | "hentai" | "smut" | "érotique" => return ContentRating::NSFW,
+			"ecchi" | "mature" | "suggestive" | "suggestif" => rating = ContentRating::Suggestive,
+			_ => {}
+		}
+	}
+	rating
+}
+
+pub fn viewer_from_tags(tags: &[String]) -> Viewer {
+	if tags.iter().any(|tag| {
+		matches!(tag.to_lowercase().as_str(), "manhwa" | "manhua" | "webtoon" | "webtoons")
+	}) {
+		Viewer::Vertical
+	} else {
+		Viewer::RightToLeft
+	}
+}
+
+/// Finds the number following `chapitre`, `chapter` or `ch.` in a title or URL slug.
+pub fn extract_chapter_number(text: &str) -> Option<f32> {
+	let lower = text.to_lowercase();
+	["chapitre", "chapter", "ch."]
+		.iter()
+		.find_map(|keyword| {
+			lower
+				.match_indices(keyword)
+				.find_map(|(pos, _)| parse_leading_number(&lower[pos + keyword.len()..]))
+		})
+}
+
+/// Volume number of a whole-volume release (`Tome 12`, `Volume 3`, `Vol. 2`).
+///
+/// Titles that also carry a chapter keyword (`Tome 3 Chapitre 20`) are chapters.
+pub fn extract_volume_number(text: &str) -> Option<f32> {
+	let lower = text.to_lowercase();
+	if ["chapitre", "chapter", "ch."].iter().any(|keyword| lower.contains(keyword)) {
+		return None;
+	}
+	["tome", "volume", "vol."].iter().find_map(|keyword| {
+		lower
+			.match_indices(keyword)
+			.filter(|(pos, _)| lower[..*pos].chars().next_back().is_none_or(|c| !c.is_alphanumeric()))
+			.find_map(|(pos, _)| parse_leading_number(&lower[pos + keyword.len()..]))
+	})
+}
+
+/// Last numeric segment of a slug (`one-piece-1100`), for chapter URLs without keyword.
+pub fn extract_trailing_number(slug: &str) -> Option<f32> {
+	slug.trim_end_matches('/')
+		.rsplit(['-', '/'])
+		.next()
+		.and_then(|segment| segment.parse::<f32>().ok())
+}
+
+/// Reads the number at the start of `text`, skipping separators (`12`, `-12-5`, ` 12,5`).
+fn parse_leading_number(text: &str) -> Option<f32> {
+	let text = text.trim_start_matches([' ', '-', '_', ':', '.']);
+	let mut number = String::new();
+	for c in text.chars() {
+		match c {
+			'0'..='9' => number.push(c),
+			'.' | ',' | '-' if !number.is_empty() && !number.contains('.') => number.push('.'),
+			_ => break,
+		}
+	}
+	number.trim_end_matches('.').parse::<f32>().ok()
+}
+
+/// Splits a raw chapter title into a clean title and the date text glued to it.
+///
+/// Chapter rows render `.chapternum` and `.chapterdate` side by side, so a title
+/// read from the link text looks like `One Piece Chapitre 45 – Le retour janvier 12, 2024`.
+/// The trailing date is cut off, the series name removed and the subtitle kept:
+/// `Chapitre 45 – Le retour`. A title that is only a date has no title left.
+pub fn clean_chapter_title(raw: &str, series_title: &str) -> (Option<String>, Option<String>) {
+	let raw = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+	let (title, date) = split_trailing_date(&raw);
+
+	let series_title = series_title.trim();
+	let mut title = title.trim();
+	if !series_title.is_empty() {
+		// Only whole words: "Solo" must not eat into "Sololeveling"
+		if let Some(rest) = strip_prefix_ignore_case(title, series_title)
+			.filter(|rest| !rest.starts_with(char::is_alphanumeric))
+		{
+			title = rest;
+		} else if let Some(rest) = strip_suffix_ignore_case(title, series_title)
+			.filter(|rest| !rest.ends_with(char::is_alphanumeric))
+		{
+			title = rest;
+		}
+	}
+	let title = title.trim_matches(|c: char| c.is_whitespace() || matches!(c, '-' | '–' | '—' | ':' | '|'));
+
+	let title = if title.is_empty() { None } else { Some(title.to_string()) };
+	(title, date)
+}
+
+/// Cuts a trailing `janvier 12, 2024`, `12 janvier 2024` or `12/01/2024` off `text`.
+fn split_trailing_date(text: &str) -> (String, Option<String>) {
+	let words: Vec<&str> = text.split(' ').collect();
+	let is_day = |word: &str| {
+		word.trim_end_matches(',')
+			.parse::<u32>()
+			.is_ok_and(|day| (1..=31).contains(&day))
+	};
+	let is_month = |word: &str| french_month_to_english(word).is_some() || english_month(word);
+
+	let date_words = match words.as_slice() {
+		[.., last] if last.split(['/', '-', '.']).count() == 3 && last.chars().all(|c| c.is_ascii_digit() || matches!(c, '/' | '-' | '.')) => 1,
+		[.., a, b, year] if year.len() == 4 && year.parse::<u32>().is_ok() && ((is_month(a) && is_day(b)) || (is_day(a) && is_month(b))) => 3,
+		_ => 0,
+	};
+	if date_words == 0 {
+		return (text.to_string(), None);
+	}
+
+	let split = words.len() - date_words;
+	(words[..split].join(" "), Some(words[split..].join(" ")))
+}
+
+fn english_month(word: &str) -> bool {
+	matches!(
+		word.trim_end_matches([',', '.']).to_lowercase().as_str(),
+		"january" | "february" | "march" | "april" | "may" | "june" | "july" | "august" | "september" | "october" | "november" | "december"
+	)
+}
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+	let mut chars = text.char_indices();
+	for expected in prefix.chars() {
+		let (_, c) = chars.next()?;
+		if !c.to_lowercase().eq(expected.to_lowercase()) {
+			return None;
+		}
+	}
+	Some(chars.as_str())
+}
+
+fn strip_suffix_ignore_case<'a>(text: &'a str, suffix: &str) -> Option<&'a str> {
+	let mut chars = text.char_indices().rev();
+	let mut start = text.len();
+	for expected in suffix.chars().rev() {
+		let (pos, c) = chars.next()?;
+		if !c.to_lowercase().eq(expected.to_lowercase()) {
+			return None;
+		}
+		start = pos;
+	}
+	Some(&text[..start])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const AUGUST_17_2025: i64 = 1755388800;
+	const JANUARY_12_2024: i64 = 1705017600;
+
+	#[test]
+	fn parses_the_configured_format() {
+		assert_eq!(parse_date("August 17, 2025", "%B %d, %Y"), Some(AUGUST_17_2025));
+		assert_eq!(parse_date("17 août 2025", "%d %B %Y"), Some(AUGUST_17_2025));
+	}
+
+	#[test]
+	fn french_months_parse_with_an_english_format() {
+		assert_eq!(parse_date("17 août 2025", "%B %d, %Y"), Some(AUGUST_17_2025));
+		assert_eq!(parse_date("janvier 12, 2024", "%B %d, %Y"), Some(JANUARY_12_2024));
+		assert_eq!(parse_date("12 Janvier 2024", "%B %d, %Y"), Some(JANUARY_12_2024));
+	}
+
+	#[test]
+	fn numeric_dates_are_day_first() {
+		assert_eq!(parse_date("17/08/2025", "%B %d, %Y"), Some(AUGUST_17_2025));
+		assert_eq!(parse_date("12/01/2024", "%d %B %Y"), Some(JANUARY_12_2024));
+	}
+
+	#[test]
+	fn rejects_non_dates() {
+		assert_eq!(parse_date("", "%B %d, %Y"), None);
+		assert_eq!(parse_date("il y a 2 jours", "%B %d, %Y"), None);
+	}
+
+	fn owned(title: &str, date: Option<&str>) -> (Option<String>, Option<String>) {
+		(Some(title.to_string()), date.map(|date| date.to_string()))
+	}
+
+	#[test]
+	fn cuts_the_glued_date_and_series_name() {
+		assert_eq!(
+			clean_chapter_title("One Piece Chapitre 45 – Le retour janvier 12, 2024", "One Piece"),
+			owned("Chapitre 45 – Le retour", Some("janvier 12, 2024"))
+		);
+		assert_eq!(
+			clean_chapter_title("  Chapitre 45\n\t One Piece  ", "One Piece"),
+			owned("Chapitre 45", None)
+		);
+	}
+
+	#[test]
+	fn splits_numeric_and_day_first_dates() {
+		assert_eq!(
+			split_trailing_date("Chapitre 12 12/01/2024"),
+			("Chapitre 12".to_string(), Some("12/01/2024".to_string()))
+		);
+		assert_eq!(
+			split_trailing_date("Chapitre 12 12 janvier 2024"),
+			("Chapitre 12".to_string(), Some("12 janvier 2024".to_string()))
+		);
+		assert_eq!(
+			split_trailing_date("Chapitre 12 August 17, 2025"),
+			("Chapitre 12".to_string(), Some("August 17, 2025".to_string()))
+		);
+		assert_eq!(split_trailing_date("Chapitre 2024"), ("Chapitre 2024".to_string(), None));
+	}
+
+	#[test]
+	fn series_name_is_only_removed_as_whole_words() {
+		assert_eq!(
+			clean_chapter_title("Sololeveling Chapitre 3", "Solo"),
+			owned("Sololeveling Chapitre 3", None)
+		);
+		assert_eq!(clean_chapter_title("Solo Chapitre 3", "Solo"), owned("Chapitre 3", None));
+		assert_eq!(clean_chapter_title("Chapitre 3 - solo", "Solo"), owned("Chapitre 3", None));
+	}
+
+	#[test]
+	fn title_that_is_only_a_date() {
+		assert_eq!(
+			clean_chapter_title("12 janvier 2024", "One Piece"),
+			(None, Some("12 janvier 2024".to_string()))
+		);
+		assert_eq!(clean_chapter_title("12/01/2024", ""), (None, Some("12/01/2024".to_string())));
+		assert_eq!(clean_chapter_title("One Piece", "One Piece"), (None, None));
+	}
+}
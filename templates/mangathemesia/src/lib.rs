@@ -0,0 +1,270 @@
+#![no_std]
+
+use aidoku::{
+	AidokuError, Chapter, FilterValue, Listing, Manga, MangaPageResult, MangaStatus, Page, Result, Viewer,
+	alloc::{String, Vec, format},
+	imports::{
+		defaults::defaults_get,
+		html::Document,
+		net::Request,
+		std::send_partial_result,
+	},
+};
+
+extern crate alloc;
+
+pub mod helper;
+pub mod parser;
+pub mod reader;
+
+use helper::{default_status_mapping, default_type_mapping, urlencode};
+use reader::PREFERRED_SERVER_SETTING;
+
+const MAX_RETRIES: u32 = 3;
+
+/// Per-site configuration of a MangaThemesia source.
+///
+/// Start from `Params::default()` and override what differs, e.g.
+/// `Params { base_url: "https://example.fr", ..Default::default() }`.
+#[derive(Clone, Copy)]
+pub struct Params {
+	pub base_url: &'static str,
+	/// Path of the catalogue and series pages (`manga` for `/manga/<slug>/`).
+	pub manga_path: &'static str,
+	pub user_agent: &'static str,
+	/// chrono format of the chapter dates, e.g. `%B %d, %Y` for `August 29, 2025`.
+	/// French month names are understood too, and the usual other layouts are
+	/// tried when a date doesn't match it.
+	pub date_format: &'static str,
+	/// Searches through WordPress' `/?s=` instead of the catalogue `title` filter.
+	pub wordpress_search: bool,
+	/// Maps the status shown on series pages.
+	pub status_mapping: fn(&str) -> MangaStatus,
+	/// Maps the type shown on series pages (Manga, Manhwa...) to a reader mode.
+	pub type_mapping: fn(&str) -> Option<Viewer>,
+	/// Chapter lists split over `?page=N`.
+	pub paginated_chapters: bool,
+	/// Series also published as whole volumes (`Tome 12`), stored as volumes
+	/// instead of chapters.
+	pub volume_releases: bool,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			base_url: "",
+			manga_path: "manga",
+			user_agent: "Mozilla/5.0 (iPhone; CPU iPhone OS 16_1_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1",
+			date_format: "%B %d, %Y",
+			wordpress_search: false,
+			status_mapping: default_status_mapping,
+			type_mapping: default_type_mapping,
+			paginated_chapters: false,
+			volume_releases: false,
+		}
+	}
+}
+
+/// Shared implementation of the MangaThemesia theme.
+///
+/// Sources wrap it and forward their `Source`, `ListingProvider` and
+/// `ImageRequestProvider` calls to it.
+pub struct MangaThemesia {
+	pub params: Params,
+}
+
+impl MangaThemesia {
+	pub fn new(params: Params) -> Self {
+		Self { params }
+	}
+
+	pub fn get_search_manga_list(
+		&self,
+		query: Option<String>,
+		page: i32,
+		filters: Vec<FilterValue>,
+	) -> Result<MangaPageResult> {
+		let mut params = Vec::new();
+
+		let query = query.filter(|query| !query.trim().is_empty());
+		if let Some(query) = &query {
+			let key = if self.params.wordpress_search { "s" } else { "title" };
+			params.push(format!("{}={}", key, urlencode(query.trim())));
+		}
+
+		for filter in filters {
+			match filter {
+				FilterValue::Select { id, value } => {
+					if !value.is_empty() {
+						params.push(format!("{}={}", id, urlencode(&value)));
+					}
+				}
+				FilterValue::Text { id, value } => {
+					if !value.trim().is_empty() {
+						params.push(format!("{}={}", id, urlencode(value.trim())));
+					}
+				}
+				FilterValue::MultiSelect { id, included, excluded } => {
+					for value in included.iter().filter(|value| !value.is_empty()) {
+						params.push(format!("{}%5B%5D={}", id, urlencode(value)));
+					}
+					for value in excluded.iter().filter(|value| !value.is_empty()) {
+						params.push(format!("{}%5B%5D=-{}", id, urlencode(value)));
+					}
+				}
+				_ => {}
+			}
+		}
+
+		if query.is_some() && self.params.wordpress_search {
+			let url = if page > 1 {
+				format!("{}/page/{}/?{}", self.params.base_url, page, params.join("&"))
+			} else {
+				format!("{}/?{}", self.params.base_url, params.join("&"))
+			};
+			return self.get_manga_page_at(&url);
+		}
+
+		self.get_manga_page(page, &params)
+	}
+
+	pub fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		match listing.id.as_str() {
+			"tendance" => return self.get_trending_manga_list(page),
+			_ => {}
+		}
+
+		let order = match listing.id.as_str() {
+			"populaire" => "popular",
+			"dernieres" | "dernières" => "update",
+			"nouveautes" => "latest",
+			_ => "",
+		};
+		self.get_manga_list_by_order(order, page)
+	}
+
+	/// Catalogue page sorted with one of the theme's `order` values
+	/// (`popular`, `update`, `latest`, `title`...).
+	pub fn get_manga_list_by_order(&self, order: &str, page: i32) -> Result<MangaPageResult> {
+		let params = if order.is_empty() {
+			Vec::new()
+		} else {
+			alloc::vec![format!("order={}", order)]
+		};
+		self.get_manga_page(page, &params)
+	}
+
+	/// Series of the home page "Populaire aujourd'hui" block, all on a single page.
+	pub fn get_trending_manga_list(&self, page: i32) -> Result<MangaPageResult> {
+		if page > 1 {
+			return Ok(MangaPageResult {
+				entries: Vec::new(),
+				has_next_page: false,
+			});
+		}
+
+		let html = self.request_html(self.params.base_url)?;
+		Ok(MangaPageResult {
+			entries: parser::parse_trending_manga(&self.params, &html),
+			has_next_page: false,
+		})
+	}
+
+	fn get_manga_page(&self, page: i32, params: &[String]) -> Result<MangaPageResult> {
+		let mut url = format!("{}/{}/?page={}", self.params.base_url, self.params.manga_path, page);
+		for param in params {
+			url.push('&');
+			url.push_str(param);
+		}
+		self.get_manga_page_at(&url)
+	}
+
+	fn get_manga_page_at(&self, url: &str) -> Result<MangaPageResult> {
+		let html = self.request_html(url)?;
+		Ok(MangaPageResult {
+			entries: parser::parse_manga_list(&self.params, &html),
+			has_next_page: parser::has_next_page(&html),
+		})
+	}
+
+	pub fn get_manga_update(&self, manga: Manga, needs_details: bool, needs_chapters: bool) -> Result<Manga> {
+		let url = self.manga_url(&manga.key);
+		let html = self.request_html(&url)?;
+
+		let mut updated = if needs_details {
+			let mut details = parser::parse_manga_details(&self.params, &html, manga.key.clone())?;
+			details.url = Some(url.clone());
+			send_partial_result(&details);
+			details
+		} else {
+			manga
+		};
+
+		if needs_chapters {
+			let mut chapters = parser::parse_chapter_list(&self.params, &html);
+			if self.params.paginated_chapters {
+				for page in 2..=parser::chapter_page_count(&html) {
+					let page_html = self.request_html(&format!("{}?page={}", url, page))?;
+					chapters.extend(parser::parse_chapter_list(&self.params, &page_html));
+				}
+			}
+			updated.chapters = Some(chapters);
+		}
+
+		Ok(updated)
+	}
+
+	pub fn get_page_list(&self, _manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
+		let url = chapter
+			.url
+			.unwrap_or_else(|| format!("{}/{}/", self.params.base_url, chapter.key));
+		let html = self.request_html(&url)?;
+
+		let preferred_server = defaults_get::<String>(PREFERRED_SERVER_SETTING)
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(0);
+
+		let pages = parser::parse_page_list(&self.params, &html, preferred_server);
+		if pages.is_empty() {
+			return Err(AidokuError::message("No pages found in chapter"));
+		}
+		Ok(pages)
+	}
+
+	pub fn get_image_request(&self, url: String) -> Result<Request> {
+		Ok(Request::get(url)?
+			.header("User-Agent", self.params.user_agent)
+			.header("Referer", self.params.base_url))
+	}
+
+	/// Series page URL. Keys may still carry the catalogue path from older versions.
+	pub fn manga_url(&self, key: &str) -> String {
+		let key = key.trim_matches('/');
+		let slug = key
+			.strip_prefix(self.params.manga_path)
+			.and_then(|rest| rest.strip_prefix('/'))
+			.unwrap_or(key);
+		format!("{}/{}/{}/", self.params.base_url, self.params.manga_path, slug)
+	}
+
+	pub fn request_html(&self, url: &str) -> Result<Document> {
+		let mut attempt = 0;
+		loop {
+			let request = Request::get(url)?
+				.header("User-Agent", self.params.user_agent)
+				.header("Accept", "text/html,application/xhtml+xml,application/xml;q=0.9,image/webp,*/*;q=0.8")
+				.header("Accept-Language", "fr-FR,fr;q=0.9,en;q=0.8")
+				.header("Referer", self.params.base_url);
+
+			match request.html() {
+				Ok(html) => return Ok(html),
+				Err(e) => {
+					if attempt >= MAX_RETRIES {
+						return Err(AidokuError::RequestError(e));
+					}
+					attempt += 1;
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,411 @@
+use aidoku::{
+	AidokuError, Chapter, Manga, MangaStatus, Page, PageContent, Result, UpdateStrategy,
+	alloc::{String, Vec, string::ToString},
+	imports::html::{Document, Element, ElementList},
+};
+use wordpress_template::{
+	cover::{is_placeholder, resolve_cover},
+	stats::{chapter_counters, count_text, stats_line, with_counters, with_header, with_related},
+};
+
+use crate::Params;
+use crate::reader::ReaderConfig;
+use crate::helper::{
+	clean_chapter_title, content_rating_from_tags, extract_chapter_number, extract_trailing_number, extract_volume_number, make_absolute_url, parse_date, relative_path,
+	viewer_from_tags,
+};
+
+/// Catalogue items, the second selector is the home page layout.
+const MANGA_SELECTORS: [&str; 2] = [".listupd .bsx", ".utao .uta .imgu"];
+const RELATED_SELECTOR: &str = "div.bixbox.related .bsx";
+/// "Populaire aujourd'hui" block of the home page.
+const TRENDING_SELECTOR: &str = "div.bixbox.hothome .bsx, .popconslide .bsx";
+const NEXT_PAGE_SELECTOR: &str =
+	".hpage a.r, .pagination a.next, a.next.page-numbers, .nextpostslink, .nav-links a[rel=next]";
+const PAGINATION_CURRENT_SELECTOR: &str = ".pagination .current, .pagination .page-numbers.current";
+const PAGINATION_LINK_SELECTOR: &str = ".pagination a.page-numbers, .pagination a";
+/// Badges overlaid on catalogue cards.
+const CARD_BADGES: [(&str, &str); 2] = [(".hotx, .hot", "HOT"), (".newx, .new, .novelabel", "NEW")];
+const COVER_SELECTOR: &str = "div.thumb img, .infomanga > div[itemprop=image] img, img[itemprop=image], .series-thumb img, .wp-post-image, div.bigcontent img";
+const DESCRIPTION_SELECTOR: &str = "div.entry-content[itemprop=description], div[itemprop=description], .desc, .summary__content, .manga-excerpt";
+const GENRE_SELECTOR: &str = "div.gnr a, .mgen a, .seriestugenre a, div.wd-full .mgen a";
+const CHAPTER_SELECTOR: &str = "div.eplister ul li, #chapterlist li, div.bxcl li, div.cl li";
+const CHAPTER_VIEWS_SELECTOR: &str = ".chapterviews, .chapter-views, .views";
+const CHAPTER_COMMENTS_SELECTOR: &str = ".chaptercomments, .chapter-comments, .comments-count";
+const RATING_SELECTOR: &str = ".rating .num, .rating-prc .num";
+const PAGE_SELECTOR: &str = "div#readerarea img";
+const MAX_CHAPTER_PAGES: i32 = 150;
+
+fn first_text(element: &Element, selector: &str) -> Option<String> {
+	element
+		.select(selector)
+		.and_then(|elements| elements.first())
+		.and_then(|element| element.text())
+		.map(|text| text.trim().to_string())
+		.filter(|text| !text.is_empty())
+}
+
+fn document_text(html: &Document, selector: &str) -> Option<String> {
+	html.select(selector)?
+		.filter_map(|element| element.text())
+		.map(|text| text.trim().to_string())
+		.find(|text| !text.is_empty())
+}
+
+/// Manga key from a series URL: the slug after the catalogue path.
+fn manga_key(params: &Params, href: &str) -> String {
+	let path = relative_path(params.base_url, href);
+	path.strip_prefix(params.manga_path)
+		.and_then(|rest| rest.strip_prefix('/'))
+		.unwrap_or(&path)
+		.to_string()
+}
+
+pub fn parse_manga_list(params: &Params, html: &Document) -> Vec<Manga> {
+	MANGA_SELECTORS
+		.iter()
+		.filter_map(|selector| html.select(selector))
+		.find(|items| !items.is_empty())
+		.map(|items| parse_cards(params, items))
+		.unwrap_or_default()
+}
+
+/// "Séries similaires" block of a series page, using the same cards as the catalogue.
+fn parse_related_manga(params: &Params, html: &Document) -> Vec<Manga> {
+	html.select(RELATED_SELECTOR)
+		.map(|items| parse_cards(params, items))
+		.unwrap_or_default()
+}
+
+/// Series of the home page "Populaire aujourd'hui" block.
+pub fn parse_trending_manga(params: &Params, html: &Document) -> Vec<Manga> {
+	html.select(TRENDING_SELECTOR)
+		.map(|items| parse_cards(params, items))
+		.unwrap_or_default()
+}
+
+fn parse_cards(params: &Params, items: ElementList) -> Vec<Manga> {
+	let mut mangas = Vec::new();
+
+	for item in items {
+		let Some(link) = item.select("a").and_then(|links| links.first()) else {
+			continue;
+		};
+		let href = link.attr("href").unwrap_or_default();
+		let key = manga_key(params, &href);
+		if key.is_empty() {
+			continue;
+		}
+
+		let title = link
+			.attr("title")
+			.map(|title| title.trim().to_string())
+			.filter(|title| !title.is_empty())
+			.or_else(|| first_text(&item, ".tt, h3, h4"))
+			.unwrap_or_default();
+		if title.is_empty() {
+			continue;
+		}
+
+		let cover = item
+			.select("img")
+			.and_then(|imgs| imgs.first())
+			.and_then(|img| resolve_cover(|name| img.attr(name)))
+			.map(|url| make_absolute_url(params.base_url, &url));
+
+		mangas.push(Manga {
+			key,
+			title,
+			cover,
+			description: card_subtitle(&item),
+			url: Some(make_absolute_url(params.base_url, &href)),
+			update_strategy: UpdateStrategy::Always,
+			..Default::default()
+		});
+	}
+
+	mangas
+}
+
+/// Latest chapter label and site badges of a catalogue card, e.g. `HOT · Chapitre 45`.
+///
+/// Search results have no subtitle field, so this ends up in the description until
+/// the details are fetched.
+fn card_subtitle(item: &Element) -> Option<String> {
+	let mut parts: Vec<String> = Vec::new();
+	for (selector, badge) in CARD_BADGES {
+		if item.select(selector).is_some_and(|badges| !badges.is_empty()) {
+			parts.push(badge.to_string());
+		}
+	}
+	if let Some(chapter) = first_text(item, ".epxs, .adds .epxs, .lch a") {
+		parts.push(chapter);
+	}
+
+	if parts.is_empty() {
+		None
+	} else {
+		Some(parts.join(" · "))
+	}
+}
+
+/// Reads the pagination block: the "Suivant" link of `.hpage`, a `next` link
+/// of numbered pagination, or a page number after the current one.
+pub fn has_next_page(html: &Document) -> bool {
+	if html
+		.select(NEXT_PAGE_SELECTOR)
+		.is_some_and(|links| !links.is_empty())
+	{
+		return true;
+	}
+
+	let page_number = |selector: &str| {
+		html.select(selector).and_then(|elements| {
+			elements
+				.filter_map(|element| element.text())
+				.filter_map(|text| text.trim().parse::<i32>().ok())
+				.max()
+		})
+	};
+	match (page_number(PAGINATION_CURRENT_SELECTOR), page_number(PAGINATION_LINK_SELECTOR)) {
+		(Some(current), Some(last)) => last > current,
+		_ => false,
+	}
+}
+
+/// Value of a series info row, whichever layout the theme version uses:
+/// `.imptdt` ("Statut <i>En cours</i>"), `.infotable` rows or `.fmed` blocks.
+/// `labels` are lowercase prefixes of the row label.
+fn info_value(html: &Document, labels: &[&str]) -> Option<String> {
+	let matches = |label: &str| {
+		let label = label.trim().to_lowercase();
+		labels.iter().any(|prefix| label.starts_with(prefix))
+	};
+
+	if let Some(rows) = html.select(".tsinfo .imptdt, .imptdt") {
+		for row in rows {
+			if matches(&row.text().unwrap_or_default()) {
+				if let Some(value) = first_text(&row, "i, a") {
+					return Some(value);
+				}
+			}
+		}
+	}
+
+	if let Some(rows) = html.select(".infotable tr") {
+		for row in rows {
+			let cells: Vec<_> = row.select("td").map(|cells| cells.collect()).unwrap_or_default();
+			if cells.len() >= 2 && matches(&cells[0].text().unwrap_or_default()) {
+				if let Some(value) = cells[1].text().map(|text| text.trim().to_string()) {
+					if !value.is_empty() {
+						return Some(value);
+					}
+				}
+			}
+		}
+	}
+
+	if let Some(blocks) = html.select(".fmed") {
+		for block in blocks {
+			if first_text(&block, "b").is_some_and(|label| matches(&label)) {
+				if let Some(value) = first_text(&block, "span") {
+					return Some(value);
+				}
+			}
+		}
+	}
+
+	None
+}
+
+fn split_people(value: Option<String>) -> Option<Vec<String>> {
+	let people: Vec<String> = value?
+		.split(',')
+		.map(|name| name.trim().to_string())
+		.filter(|name| !name.is_empty() && name != "-" && !name.eq_ignore_ascii_case("n/a"))
+		.collect();
+	if people.is_empty() { None } else { Some(people) }
+}
+
+pub fn parse_manga_details(params: &Params, html: &Document, key: String) -> Result<Manga> {
+	let title = document_text(html, "h1.entry-title")
+		.or_else(|| document_text(html, ".ts-breadcrumb li:last-child span"))
+		.or_else(|| document_text(html, "h1"))
+		.ok_or_else(|| AidokuError::message("Failed to parse manga title"))?;
+
+	let cover = html
+		.select(COVER_SELECTOR)
+		.and_then(|imgs| imgs.filter_map(|img| resolve_cover(|name| img.attr(name))).next())
+		.map(|url| make_absolute_url(params.base_url, &url));
+
+	let rating = document_text(html, RATING_SELECTOR)
+		.or_else(|| {
+			html.select("meta[itemprop=ratingValue]")
+				.and_then(|metas| metas.first())
+				.and_then(|meta| meta.attr("content"))
+		})
+		.filter(|rating| !matches!(rating.trim(), "" | "0"));
+	let views = info_value(html, &["vues", "views"]).and_then(|views| count_text(&views));
+	let stats = stats_line(&[("Note", rating.as_deref()), ("Vues", views.as_deref())]);
+	let description = with_related(
+		with_header(document_text(html, DESCRIPTION_SELECTOR), stats.as_slice()),
+		parse_related_manga(params, html).iter().map(|manga| manga.title.as_str()),
+	);
+
+	let authors = split_people(info_value(html, &["auteur", "author"]));
+	let artists = split_people(info_value(html, &["artiste", "artist", "dessinateur"]));
+
+	let status = info_value(html, &["statut", "status"])
+		.map(|status| (params.status_mapping)(&status))
+		.unwrap_or(MangaStatus::Unknown);
+
+	let mut tags: Vec<String> = Vec::new();
+	if let Some(genres) = html.select(GENRE_SELECTOR) {
+		for genre in genres {
+			let genre = genre.text().unwrap_or_default().trim().to_string();
+			if !genre.is_empty() && !tags.contains(&genre) {
+				tags.push(genre);
+			}
+		}
+	}
+
+	let viewer = info_value(html, &["type"])
+		.and_then(|manga_type| (params.type_mapping)(&manga_type))
+		.unwrap_or_else(|| viewer_from_tags(&tags));
+	let content_rating = content_rating_from_tags(&tags);
+
+	Ok(Manga {
+		key,
+		title,
+		cover,
+		authors,
+		artists,
+		description,
+		tags: if tags.is_empty() { None } else { Some(tags) },
+		status,
+		content_rating,
+		viewer,
+		update_strategy: UpdateStrategy::Always,
+		..Default::default()
+	})
+}
+
+pub fn parse_chapter_list(params: &Params, html: &Document) -> Vec<Chapter> {
+	let mut chapters = Vec::new();
+
+	let Some(items) = html.select(CHAPTER_SELECTOR) else {
+		return chapters;
+	};
+	let series_title = document_text(html, "h1.entry-title").unwrap_or_default();
+
+	for item in items {
+		let Some(link) = item.select("a").and_then(|links| links.first()) else {
+			continue;
+		};
+		let href = link.attr("href").unwrap_or_default();
+		let key = relative_path(params.base_url, &href);
+		if key.is_empty() {
+			continue;
+		}
+
+		let raw_title = first_text(&item, ".chapternum")
+			.or_else(|| first_text(&item, ".lch a"))
+			.or_else(|| link.text().map(|text| text.trim().to_string()))
+			.unwrap_or_default();
+		let (title, title_date) = clean_chapter_title(&raw_title, &series_title);
+
+		let volume_number = if params.volume_releases {
+			extract_volume_number(title.as_deref().unwrap_or(&key))
+		} else {
+			None
+		};
+
+		// `data-num` holds the chapter number on recent theme versions, but also
+		// the volume number of whole-volume releases. Those keep no chapter number
+		// so "Tome 12" doesn't collide with chapter 12, and stay in site order.
+		let chapter_number = if volume_number.is_some() {
+			None
+		} else {
+			item.attr("data-num")
+				.and_then(|num| num.trim().replace(',', ".").parse::<f32>().ok())
+				.or_else(|| title.as_deref().and_then(extract_chapter_number))
+				.or_else(|| extract_chapter_number(&key))
+				.or_else(|| extract_trailing_number(&key))
+		};
+
+		let date_uploaded = first_text(&item, ".chapterdate")
+			.or(title_date)
+			.and_then(|date| parse_date(&date, params.date_format));
+
+		let counters = chapter_counters(
+			first_text(&item, CHAPTER_VIEWS_SELECTOR).and_then(|views| count_text(&views)),
+			first_text(&item, CHAPTER_COMMENTS_SELECTOR).and_then(|comments| count_text(&comments)),
+		);
+
+		chapters.push(Chapter {
+			url: Some(make_absolute_url(params.base_url, &href)),
+			key,
+			title: with_counters(title, counters),
+			chapter_number,
+			volume_number,
+			date_uploaded,
+			language: Some(String::from("fr")),
+			..Default::default()
+		});
+	}
+
+	chapters
+}
+
+/// Number of chapter list pages on sites that paginate them (`?page=N`).
+pub fn chapter_page_count(html: &Document) -> i32 {
+	html.select(".pagination a, .pagination span, .page-numbers")
+		.map(|links| {
+			links
+				.filter_map(|link| link.text())
+				.filter_map(|text| text.trim().parse::<i32>().ok())
+				.max()
+				.unwrap_or(1)
+		})
+		.unwrap_or(1)
+		.clamp(1, MAX_CHAPTER_PAGES)
+}
+
+/// Chapter images, from the `ts_reader.run` config when the page has one since it
+/// lists every server, otherwise from the `div#readerarea` images.
+pub fn parse_page_list(params: &Params, html: &Document, preferred_server: usize) -> Vec<Page> {
+	let mut urls = html
+		.select("script")
+		.and_then(|scripts| {
+			scripts
+				.filter_map(|script| script.data())
+				.find(|data| data.contains("ts_reader.run"))
+		})
+		.and_then(|script| ReaderConfig::from_script(&script))
+		.map(|config| config.images(preferred_server))
+		.unwrap_or_default();
+
+	if urls.is_empty() {
+		if let Some(images) = html.select(PAGE_SELECTOR) {
+			for img in images {
+				let url = ["data-src", "data-lazy-src", "data-cfsrc", "src"]
+					.iter()
+					.filter_map(|name| img.attr(name))
+					.map(|url| url.trim().to_string())
+					.find(|url| !is_placeholder(url));
+				if let Some(url) = url {
+					urls.push(url);
+				}
+			}
+		}
+	}
+
+	urls.into_iter()
+		.map(|url| Page {
+			content: PageContent::url(make_absolute_url(params.base_url, &url)),
+			thumbnail: None,
+			has_description: false,
+			description: None,
+		})
+		.collect()
+}
@@ -0,0 +1,136 @@
+use aidoku::{
+	alloc::{String, Vec},
+	serde::Deserialize,
+};
+
+/// Setting holding the index of the preferred image server.
+pub const PREFERRED_SERVER_SETTING: &str = "preferredServer";
+
+/// Payload of the `ts_reader.run({...})` call of MangaThemesia reader pages.
+#[derive(Deserialize, Debug, Default)]
+pub struct ReaderConfig {
+	#[serde(default)]
+	pub sources: Vec<ReaderSource>,
+}
+
+/// One image server ("Server 1", mirrors...) of the reader.
+#[derive(Deserialize, Debug, Default)]
+pub struct ReaderSource {
+	#[serde(default)]
+	pub source: String,
+	#[serde(default)]
+	pub images: Vec<String>,
+}
+
+impl ReaderConfig {
+	/// Parses the reader config out of the script containing `ts_reader.run`.
+	pub fn from_script(script: &str) -> Option<Self> {
+		let call = script.find("ts_reader.run(")?;
+		let json = extract_json_object(&script[call..])?;
+		serde_json::from_str(json).ok()
+	}
+
+	/// Images of the preferred server, or of the first server that has images
+	/// when it doesn't exist or is empty.
+	pub fn images(&self, preferred_server: usize) -> Vec<String> {
+		let with_images = |source: &&ReaderSource| source.images.iter().any(|url| !url.trim().is_empty());
+
+		self.sources
+			.get(preferred_server)
+			.filter(with_images)
+			.or_else(|| self.sources.iter().find(with_images))
+			.map(|source| {
+				source
+					.images
+					.iter()
+					.map(|url| String::from(url.trim()))
+					.filter(|url| !url.is_empty())
+					.collect()
+			})
+			.unwrap_or_default()
+	}
+}
+
+/// The first balanced `{...}` object of `text`, skipping braces inside strings.
+fn extract_json_object(text: &str) -> Option<&str> {
+	let start = text.find('{')?;
+	let mut depth = 0;
+	let mut in_string = false;
+	let mut escaped = false;
+
+	for (offset, c) in text[start..].char_indices() {
+		if in_string {
+			match c {
+				_ if escaped => escaped = false,
+				'\\' => escaped = true,
+				'"' => in_string = false,
+				_ => {}
+			}
+			continue;
+		}
+
+		match c {
+			'"' => in_string = true,
+			'{' => depth += 1,
+			'}' => {
+				depth -= 1;
+				if depth == 0 {
+					return Some(&text[start..=start + offset]);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// Inline script of a SushiScans reader page
+	const READER_SCRIPT: &str = r#"ts_reader.run({"post_id":48211,"noimagehtml":"<style>.ts-main-image{display:none}<\/style><center>Aucune image {\"erreur\"}<\/center>","prevUrl":"https:\/\/sushiscan.net\/one-piece-volume-104\/","nextUrl":"","mode":"full","sources":[{"source":"Server 1","images":["https:\/\/sushiscan.net\/wp-content\/uploads\/2024\/01\/OP104-001.jpg"," https:\/\/sushiscan.net\/wp-content\/uploads\/2024\/01\/OP104-002.jpg ",""]},{"source":"Server 2","images":["https:\/\/cdn.sushiscan.net\/OP104-001.jpg","https:\/\/cdn.sushiscan.net\/OP104-002.jpg"]},{"source":"Server 3","images":[]}],"lazyload":false,"defaultSource":"Server 1","lazyloadOptions":{"threshold":1000}});"#;
+
+	#[test]
+	fn extracts_the_object_despite_braces_in_strings() {
+		let json = extract_json_object(READER_SCRIPT).unwrap();
+		assert!(json.starts_with("{\"post_id\":48211,"));
+		assert!(json.ends_with("\"lazyloadOptions\":{\"threshold\":1000}}"));
+		assert_eq!(extract_json_object("ts_reader.run({\"sources\":["), None);
+	}
+
+	#[test]
+	fn reads_every_server() {
+		let config = ReaderConfig::from_script(READER_SCRIPT).unwrap();
+		let servers: Vec<&str> = config.sources.iter().map(|source| source.source.as_str()).collect();
+		assert_eq!(servers, ["Server 1", "Server 2", "Server 3"]);
+		assert!(ReaderConfig::from_script("var ts_reader = {};").is_none());
+	}
+
+	#[test]
+	fn uses_the_preferred_server() {
+		let config = ReaderConfig::from_script(READER_SCRIPT).unwrap();
+		assert_eq!(
+			config.images(0),
+			[
+				"https://sushiscan.net/wp-content/uploads/2024/01/OP104-001.jpg",
+				"https://sushiscan.net/wp-content/uploads/2024/01/OP104-002.jpg",
+			]
+		);
+		assert_eq!(
+			config.images(1),
+			["https://cdn.sushiscan.net/OP104-001.jpg", "https://cdn.sushiscan.net/OP104-002.jpg"]
+		);
+	}
+
+	#[test]
+	fn falls_back_to_the_first_server_with_images() {
+		let config = ReaderConfig::from_script(READER_SCRIPT).unwrap();
+		// Empty server
+		assert_eq!(config.images(2), config.images(0));
+		// Missing server
+		assert_eq!(config.images(7), config.images(0));
+		assert!(ReaderConfig::default().images(0).is_empty());
+	}
+}
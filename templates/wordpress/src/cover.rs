@@ -0,0 +1,238 @@
+use alloc::string::{String, ToString};
+
+/// Attributes holding a single image URL, in order of preference.
+/// Lazy-load plugins keep the real image in `data-*` while `src` holds a placeholder.
+const URL_ATTRIBUTES: [&str; 2] = ["data-src", "data-lazy-src"];
+
+/// Attributes holding a `srcset` candidate list.
+const SRCSET_ATTRIBUTES: [&str; 3] = ["data-srcset", "data-lazy-srcset", "srcset"];
+
+/// Attributes checked once everything else failed.
+const FALLBACK_ATTRIBUTES: [&str; 2] = ["src", "data-cfsrc"];
+
+/// Resolves the full resolution cover of an `<img>` element.
+///
+/// `attr` returns the value of the named attribute, e.g. `|name| img.attr(name)`.
+/// Candidates are tried in order (`data-src`, `data-lazy-src`, largest `srcset`
+/// entry, `src`), lazy-load placeholders are skipped and WordPress thumbnail
+/// suffixes (`-175x238`) are removed from the result.
+pub fn resolve_cover<F>(attr: F) -> Option<String>
+where
+	F: Fn(&str) -> Option<String>,
+{
+	let single = |name: &str| attr(name).filter(|url| !is_placeholder(url));
+
+	URL_ATTRIBUTES
+		.iter()
+		.find_map(|name| single(name))
+		.or_else(|| {
+			SRCSET_ATTRIBUTES.iter().find_map(|name| {
+				attr(name).and_then(|srcset| best_srcset_candidate(&srcset).map(String::from))
+			})
+		})
+		.or_else(|| FALLBACK_ATTRIBUTES.iter().find_map(|name| single(name)))
+		.map(|url| strip_thumbnail_suffix(url.trim()))
+}
+
+/// Returns the URL with the largest width (`w`) or density (`x`) descriptor
+/// from a `srcset` value, ignoring placeholders.
+pub fn best_srcset_candidate(srcset: &str) -> Option<&str> {
+	let mut best: Option<(&str, f32)> = None;
+	let mut rest = srcset;
+
+	// URLs may contain commas (`data:` URIs), so a candidate's URL runs up to the
+	// next whitespace and only its descriptor ends at a comma
+	loop {
+		rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+		if rest.is_empty() {
+			break;
+		}
+		let url_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+		let (url, after_url) = rest.split_at(url_end);
+		let (url, descriptor, next) = match url.strip_suffix(',') {
+			Some(url) => (url.trim_end_matches(','), "", after_url),
+			None => {
+				let descriptor_end = after_url.find(',').unwrap_or(after_url.len());
+				(url, &after_url[..descriptor_end], &after_url[descriptor_end..])
+			}
+		};
+		rest = next;
+		if is_placeholder(url) {
+			continue;
+		}
+
+		// A candidate without descriptor counts as `1x`
+		let size = descriptor
+			.split_whitespace()
+			.next()
+			.and_then(|descriptor| {
+				descriptor
+					.strip_suffix('w')
+					.or_else(|| descriptor.strip_suffix('x'))
+					.and_then(|value| value.parse::<f32>().ok())
+			})
+			.unwrap_or(1.0);
+
+		if best.is_none_or(|(_, best_size)| size > best_size) {
+			best = Some((url, size));
+		}
+	}
+
+	best.map(|(url, _)| url)
+}
+
+/// Whether the URL is a lazy-load placeholder rather than a real cover.
+pub fn is_placeholder(url: &str) -> bool {
+	let url = url.trim();
+	if url.is_empty() || url.starts_with("data:") {
+		return true;
+	}
+
+	let path = url.split(['?', '#']).next().unwrap_or(url).to_lowercase();
+	path.ends_with(".svg")
+		|| path.ends_with("/lazy.gif")
+		|| path.ends_with("/blank.gif")
+		|| path.ends_with("/loading.gif")
+		|| path.contains("placeholder")
+}
+
+/// Removes the WordPress `-WxH` thumbnail suffix and Jetpack resize parameters
+/// so `cover-175x238.jpg?resize=165,225` becomes `cover.jpg`.
+pub fn strip_thumbnail_suffix(url: &str) -> String {
+	let (path, query) = match url.find('?') {
+		Some(pos) => (&url[..pos], &url[pos..]),
+		None => (url, ""),
+	};
+	let query = if query.starts_with("?resize=") || query.starts_with("?fit=") {
+		""
+	} else {
+		query
+	};
+
+	let file_start = path.rfind('/').map(|pos| pos + 1).unwrap_or(0);
+	let Some(ext_pos) = path[file_start..].rfind('.').map(|pos| file_start + pos) else {
+		return url.to_string();
+	};
+
+	let stem = &path[file_start..ext_pos];
+	let stripped_len = stem
+		.rfind('-')
+		.filter(|&dash| is_dimensions(&stem[dash + 1..]))
+		.unwrap_or(stem.len());
+
+	let mut result = String::with_capacity(url.len());
+	result.push_str(&path[..file_start + stripped_len]);
+	result.push_str(&path[ext_pos..]);
+	result.push_str(query);
+	result
+}
+
+/// Matches `175x238`.
+fn is_dimensions(value: &str) -> bool {
+	let Some((width, height)) = value.split_once('x') else {
+		return false;
+	};
+	!width.is_empty()
+		&& !height.is_empty()
+		&& width.bytes().all(|b| b.is_ascii_digit())
+		&& height.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn strips_thumbnail_suffixes() {
+		assert_eq!(
+			strip_thumbnail_suffix("https://example.com/wp-content/uploads/2024/05/cover-175x238.jpg"),
+			"https://example.com/wp-content/uploads/2024/05/cover.jpg"
+		);
+		assert_eq!(
+			strip_thumbnail_suffix("https://example.com/uploads/one-piece-193x278.webp"),
+			"https://example.com/uploads/one-piece.webp"
+		);
+		// Only a trailing WxH is a thumbnail suffix
+		assert_eq!(
+			strip_thumbnail_suffix("https://example.com/uploads/solo-leveling-2.jpg"),
+			"https://example.com/uploads/solo-leveling-2.jpg"
+		);
+		assert_eq!(
+			strip_thumbnail_suffix("https://example.com/uploads/cover-x238.jpg"),
+			"https://example.com/uploads/cover-x238.jpg"
+		);
+		assert_eq!(strip_thumbnail_suffix("https://example.com/cover"), "https://example.com/cover");
+	}
+
+	#[test]
+	fn drops_resize_parameters_only() {
+		assert_eq!(
+			strip_thumbnail_suffix("https://i0.wp.com/example.com/cover-175x238.jpg?resize=165,225"),
+			"https://i0.wp.com/example.com/cover.jpg"
+		);
+		assert_eq!(
+			strip_thumbnail_suffix("https://i0.wp.com/example.com/cover.jpg?fit=720,1024&ssl=1"),
+			"https://i0.wp.com/example.com/cover.jpg"
+		);
+		assert_eq!(
+			strip_thumbnail_suffix("https://example.com/cover-175x238.jpg?ver=2"),
+			"https://example.com/cover.jpg?ver=2"
+		);
+		// A dot in the query isn't the extension
+		assert_eq!(
+			strip_thumbnail_suffix("https://example.com/image?name=cover-175x238.jpg"),
+			"https://example.com/image?name=cover-175x238.jpg"
+		);
+	}
+
+	#[test]
+	fn picks_the_largest_srcset_candidate() {
+		assert_eq!(
+			best_srcset_candidate("https://example.com/c-110x150.jpg 110w, https://example.com/c-350x476.jpg 350w, https://example.com/c-175x238.jpg 175w"),
+			Some("https://example.com/c-350x476.jpg")
+		);
+		assert_eq!(
+			best_srcset_candidate("https://example.com/c.jpg, https://example.com/c@2x.jpg 2x, https://example.com/c@1.5x.jpg 1.5x"),
+			Some("https://example.com/c@2x.jpg")
+		);
+		assert_eq!(
+			best_srcset_candidate("data:image/gif;base64,R0lGOD 1000w, https://example.com/c.jpg 300w"),
+			Some("https://example.com/c.jpg")
+		);
+		assert_eq!(
+			best_srcset_candidate("https://example.com/c.jpg 1x,https://example.com/c@3x.jpg 3x"),
+			Some("https://example.com/c@3x.jpg")
+		);
+		assert_eq!(best_srcset_candidate(""), None);
+		assert_eq!(best_srcset_candidate(" , "), None);
+	}
+
+	#[test]
+	fn detects_placeholders() {
+		assert!(is_placeholder(""));
+		assert!(is_placeholder("  "));
+		assert!(is_placeholder("data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'%3E%3C/svg%3E"));
+		assert!(is_placeholder("https://example.com/wp-content/plugins/lazy-load/images/lazy.gif"));
+		assert!(is_placeholder("https://example.com/wp-content/themes/madara/images/dflazy.svg?v=2"));
+		assert!(is_placeholder("https://example.com/images/Placeholder-cover.png"));
+		assert!(!is_placeholder("https://example.com/wp-content/uploads/cover.jpg"));
+		assert!(!is_placeholder("https://example.com/uploads/blank-page.jpg"));
+	}
+
+	#[test]
+	fn resolves_lazy_loaded_covers() {
+		let attrs = [
+			("src", "data:image/gif;base64,R0lGODlhAQABAAAAACw="),
+			("data-src", "https://example.com/loading.gif"),
+			("data-srcset", "https://example.com/cover-110x150.jpg 110w, https://example.com/cover-350x476.jpg 350w"),
+		];
+		let attr = |name: &str| attrs.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string());
+		assert_eq!(resolve_cover(attr), Some("https://example.com/cover.jpg".to_string()));
+
+		let attr = |name: &str| (name == "src").then(|| " https://example.com/cover-175x238.jpg ".to_string());
+		assert_eq!(resolve_cover(attr), Some("https://example.com/cover.jpg".to_string()));
+
+		let attr = |name: &str| (name == "src").then(|| "data:image/gif;base64,R0lGODlh".to_string());
+		assert_eq!(resolve_cover(attr), None);
+	}
+}
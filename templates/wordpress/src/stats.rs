@@ -0,0 +1,200 @@
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+
+/// Reads the counter in a label such as `1.2K vues`, `👁 1 234` or `(12)`.
+///
+/// The counter is kept as displayed; `None` when there is no digit or it is zero.
+pub fn count_text(text: &str) -> Option<String> {
+	let start = text.find(|c: char| c.is_ascii_digit())?;
+	let mut count = String::new();
+	let mut chars = text[start..].chars().peekable();
+	while let Some(c) = chars.next() {
+		match c {
+			'0'..='9' | '.' | ',' => count.push(c),
+			// "1 234": thousands separated by a (narrow no-break) space
+			' ' | '\u{a0}' | '\u{202f}' if chars.peek().is_some_and(char::is_ascii_digit) => count.push(c),
+			'k' | 'K' | 'm' | 'M' => {
+				count.push(c.to_ascii_uppercase());
+				break;
+			}
+			_ => break,
+		}
+	}
+
+	let count = count.trim_end_matches(['.', ',']);
+	if count.trim_start_matches(['0', '.', ',']).is_empty() {
+		None
+	} else {
+		Some(count.to_string())
+	}
+}
+
+/// Monthly views out of a Madara "Rang" row: `12th, it has 1.2K monthly views`
+/// or `12e, il a 1,2K vues mensuelles`.
+pub fn monthly_views_from_rank(text: &str) -> Option<String> {
+	let pos = find_ignore_ascii_case(text, " has ").or_else(|| find_ignore_ascii_case(text, " a "))?;
+	count_text(&text[pos..])
+}
+
+/// Byte offset of `marker` in `text`, ignoring ASCII case.
+fn find_ignore_ascii_case(text: &str, marker: &str) -> Option<usize> {
+	text.char_indices()
+		.map(|(pos, _)| pos)
+		.find(|&pos| text[pos..].get(..marker.len()).is_some_and(|candidate| candidate.eq_ignore_ascii_case(marker)))
+}
+
+/// `1,2K vues • 3 commentaires`, for whichever counters a chapter row has.
+pub fn chapter_counters(views: Option<String>, comments: Option<String>) -> Option<String> {
+	let mut parts = Vec::new();
+	if let Some(views) = views {
+		parts.push(format!("{} vues", views));
+	}
+	if let Some(comments) = comments {
+		parts.push(format!("{} commentaires", comments));
+	}
+	if parts.is_empty() {
+		None
+	} else {
+		Some(parts.join(" • "))
+	}
+}
+
+/// Appends chapter counters to a chapter title: `Chapitre 12 (1,2K vues)`.
+pub fn with_counters(title: Option<String>, counters: Option<String>) -> Option<String> {
+	match (title, counters) {
+		(Some(title), Some(counters)) => Some(format!("{} ({})", title, counters)),
+		(None, Some(counters)) => Some(counters),
+		(title, None) => title,
+	}
+}
+
+/// `Note : 4,5 • Vues : 1,2K`, for whichever `(label, value)` stats are present.
+pub fn stats_line(stats: &[(&str, Option<&str>)]) -> Option<String> {
+	let parts: Vec<String> = stats
+		.iter()
+		.filter_map(|(label, value)| value.map(|value| format!("{} : {}", label, value.trim())))
+		.collect();
+	if parts.is_empty() {
+		None
+	} else {
+		Some(parts.join(" • "))
+	}
+}
+
+/// Puts header lines (stats, alternative titles...) above the synopsis.
+pub fn with_header(synopsis: Option<String>, header: &[String]) -> Option<String> {
+	if header.is_empty() {
+		return synopsis;
+	}
+	let header = header.join("\n");
+	match synopsis {
+		Some(synopsis) => Some(format!("{}\n\n{}", header, synopsis)),
+		None => Some(header),
+	}
+}
+
+/// Lists the related series of a series page below its synopsis:
+/// `Séries similaires : A, B`.
+pub fn with_related<I, S>(synopsis: Option<String>, titles: I) -> Option<String>
+where
+	I: IntoIterator<Item = S>,
+	S: AsRef<str>,
+{
+	let titles: Vec<String> = titles
+		.into_iter()
+		.map(|title| title.as_ref().trim().to_string())
+		.filter(|title| !title.is_empty())
+		.collect();
+	if titles.is_empty() {
+		return synopsis;
+	}
+	let related = format!("Séries similaires : {}", titles.join(", "));
+	match synopsis {
+		Some(synopsis) => Some(format!("{}\n\n{}", synopsis, related)),
+		None => Some(related),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn counts_are_kept_as_displayed() {
+		assert_eq!(count_text("1.2K vues"), Some("1.2K".to_string()));
+		assert_eq!(count_text("👁 1 234"), Some("1 234".to_string()));
+		assert_eq!(count_text("1\u{202f}234 vues"), Some("1\u{202f}234".to_string()));
+		assert_eq!(count_text("(12)"), Some("12".to_string()));
+		assert_eq!(count_text("3,5m"), Some("3,5M".to_string()));
+		assert_eq!(count_text("12. "), Some("12".to_string()));
+		assert_eq!(count_text("0 vues"), None);
+		assert_eq!(count_text("Aucune vue"), None);
+	}
+
+	#[test]
+	fn monthly_views_follow_the_rank() {
+		assert_eq!(monthly_views_from_rank("12th, it has 1.2K monthly views"), Some("1.2K".to_string()));
+		assert_eq!(monthly_views_from_rank("12e, il a 1,2K vues mensuelles"), Some("1,2K".to_string()));
+		assert_eq!(monthly_views_from_rank("12th, It HAS 300 monthly views"), Some("300".to_string()));
+		assert_eq!(monthly_views_from_rank("12e"), None);
+		assert_eq!(monthly_views_from_rank("1 234"), None);
+	}
+
+	#[test]
+	fn rank_with_case_changing_characters_does_not_panic() {
+		// `İ` lowercases to three bytes instead of two
+		assert_eq!(monthly_views_from_rank("İİİ 12e, il a 1,2K vues mensuelles"), Some("1,2K".to_string()));
+		assert_eq!(monthly_views_from_rank("İstanbul a 5K vues"), Some("5K".to_string()));
+		assert_eq!(monthly_views_from_rank("ÉTÉ"), None);
+	}
+
+	#[test]
+	fn stats_line_skips_missing_stats() {
+		assert_eq!(
+			stats_line(&[("Note", Some(" 4,5 ")), ("Type", None), ("Vues", Some("1,2K"))]),
+			Some("Note : 4,5 • Vues : 1,2K".to_string())
+		);
+		assert_eq!(stats_line(&[("Note", None)]), None);
+	}
+
+	#[test]
+	fn header_goes_above_the_synopsis() {
+		let header = ["Titres alternatifs : A".to_string(), "Note : 4,5".to_string()];
+		assert_eq!(
+			with_header(Some("Synopsis".to_string()), &header),
+			Some("Titres alternatifs : A\nNote : 4,5\n\nSynopsis".to_string())
+		);
+		assert_eq!(with_header(None, &header[1..]), Some("Note : 4,5".to_string()));
+		assert_eq!(with_header(Some("Synopsis".to_string()), &[]), Some("Synopsis".to_string()));
+	}
+
+	#[test]
+	fn counters_follow_the_chapter_title() {
+		let counters = chapter_counters(Some("1,2K".to_string()), Some("3".to_string()));
+		assert_eq!(counters.as_deref(), Some("1,2K vues • 3 commentaires"));
+		assert_eq!(
+			with_counters(Some("Chapitre 12".to_string()), counters),
+			Some("Chapitre 12 (1,2K vues • 3 commentaires)".to_string())
+		);
+		assert_eq!(
+			with_counters(None, chapter_counters(None, Some("3".to_string()))),
+			Some("3 commentaires".to_string())
+		);
+		assert_eq!(with_counters(Some("Chapitre 12".to_string()), None), Some("Chapitre 12".to_string()));
+		assert_eq!(chapter_counters(None, None), None);
+	}
+
+	#[test]
+	fn related_series_follow_the_synopsis() {
+		assert_eq!(
+			with_related(Some("Synopsis".to_string()), [" A ", "", "B"]),
+			Some("Synopsis\n\nSéries similaires : A, B".to_string())
+		);
+		assert_eq!(with_related(None, ["A"]), Some("Séries similaires : A".to_string()));
+		assert_eq!(with_related(Some("Synopsis".to_string()), [""; 0]), Some("Synopsis".to_string()));
+		assert_eq!(with_related(None, ["  "]), None);
+	}
+}